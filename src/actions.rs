@@ -0,0 +1,16 @@
+mod action;
+mod params;
+mod resources;
+
+pub use action::*;
+use bevy::prelude::*;
+pub use params::*;
+pub use resources::*;
+
+pub struct ActionsPlugin;
+
+impl Plugin for ActionsPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(InputBindings::load());
+    }
+}
@@ -0,0 +1,79 @@
+use serde::{Deserialize, Serialize};
+
+/// Logical game action, decoupled from the physical key that triggers it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Action {
+    CameraForward,
+    CameraBack,
+    CameraLeft,
+    CameraRight,
+    CameraRotateLeft,
+    CameraRotateRight,
+    ToggleTopDown,
+    FollowTarget,
+    BuildTomato,
+    BuildPotato,
+    BuildCabbage,
+    BuildPeaShooter,
+    BuildLaserCarrot,
+    BuildCompost,
+    BuildPumpkinPatch,
+    Upgrade,
+    Sell,
+    CycleSelection,
+    CallWave,
+    ExitGame,
+    ToggleInspector,
+}
+
+impl Action {
+    pub const ALL: [Action; 21] = [
+        Action::CameraForward,
+        Action::CameraBack,
+        Action::CameraLeft,
+        Action::CameraRight,
+        Action::CameraRotateLeft,
+        Action::CameraRotateRight,
+        Action::ToggleTopDown,
+        Action::FollowTarget,
+        Action::BuildTomato,
+        Action::BuildPotato,
+        Action::BuildCabbage,
+        Action::BuildPeaShooter,
+        Action::BuildLaserCarrot,
+        Action::BuildCompost,
+        Action::BuildPumpkinPatch,
+        Action::Upgrade,
+        Action::Sell,
+        Action::CycleSelection,
+        Action::CallWave,
+        Action::ExitGame,
+        Action::ToggleInspector,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Action::CameraForward => "Camera Forward",
+            Action::CameraBack => "Camera Back",
+            Action::CameraLeft => "Camera Left",
+            Action::CameraRight => "Camera Right",
+            Action::CameraRotateLeft => "Rotate Left",
+            Action::CameraRotateRight => "Rotate Right",
+            Action::ToggleTopDown => "Top-Down View",
+            Action::FollowTarget => "Follow Enemy",
+            Action::BuildTomato => "Build Tomato",
+            Action::BuildPotato => "Build Potato",
+            Action::BuildCabbage => "Build Cabbage",
+            Action::BuildPeaShooter => "Build Pea Shooter",
+            Action::BuildLaserCarrot => "Build Laser Carrot",
+            Action::BuildCompost => "Build Compost Heap",
+            Action::BuildPumpkinPatch => "Build Pumpkin Patch",
+            Action::Upgrade => "Upgrade",
+            Action::Sell => "Sell",
+            Action::CycleSelection => "Cycle Selection",
+            Action::CallWave => "Call Next Wave",
+            Action::ExitGame => "Exit Game",
+            Action::ToggleInspector => "Toggle Inspector",
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use bevy::{ecs::system::SystemParam, prelude::*};
+
+use super::{action::Action, resources::InputBindings};
+
+/// Reads keyboard state through the current `InputBindings`.
+#[derive(SystemParam)]
+pub struct ActionInput<'w> {
+    keyboard: Res<'w, Input<KeyCode>>,
+    bindings: Res<'w, InputBindings>,
+}
+
+impl<'w> ActionInput<'w> {
+    pub fn pressed(&self, action: Action) -> bool {
+        self.bindings
+            .key(action)
+            .is_some_and(|key| self.keyboard.pressed(key))
+    }
+
+    pub fn just_pressed(&self, action: Action) -> bool {
+        self.bindings
+            .key(action)
+            .is_some_and(|key| self.keyboard.just_pressed(key))
+    }
+}
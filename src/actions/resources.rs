@@ -0,0 +1,126 @@
+use bevy::{prelude::*, utils::HashMap};
+use serde::{Deserialize, Serialize};
+
+use crate::config::{load_config, save_config};
+
+use super::action::Action;
+
+const BINDINGS_FILE: &str = "bindings.ron";
+
+/// Which key triggers each `Action`.
+#[derive(Resource, Debug, Clone, Serialize, Deserialize)]
+pub struct InputBindings {
+    pub keys: HashMap<Action, KeyCode>,
+}
+
+impl InputBindings {
+    /// Loads the saved bindings, falling back to the defaults for anything missing.
+    pub fn load() -> Self {
+        load_config::<InputBindings>(BINDINGS_FILE)
+            .map(Self::with_defaults)
+            .unwrap_or_default()
+    }
+
+    /// Gives actions added since the file was written their default key if it's free.
+    fn with_defaults(mut self) -> Self {
+        for (action, key) in Self::default().keys {
+            if !self.keys.contains_key(&action) && !self.keys.values().any(|k| *k == key) {
+                self.keys.insert(action, key);
+            }
+        }
+        self
+    }
+
+    pub fn save(&self) {
+        save_config(BINDINGS_FILE, self);
+    }
+
+    pub fn key(&self, action: Action) -> Option<KeyCode> {
+        self.keys.get(&action).copied()
+    }
+
+    /// Binds `key` to `action`, unbinding it from any other action that used it.
+    pub fn bind(&mut self, action: Action, key: KeyCode) {
+        self.keys.retain(|_, bound| *bound != key);
+        self.keys.insert(action, key);
+    }
+}
+
+impl Default for InputBindings {
+    fn default() -> Self {
+        // The camera pans on the arrow keys so that letters stay free for hotkeys
+        let keys = [
+            (Action::CameraForward, KeyCode::Up),
+            (Action::CameraBack, KeyCode::Down),
+            (Action::CameraLeft, KeyCode::Left),
+            (Action::CameraRight, KeyCode::Right),
+            (Action::CameraRotateLeft, KeyCode::Q),
+            (Action::CameraRotateRight, KeyCode::E),
+            (Action::ToggleTopDown, KeyCode::V),
+            (Action::FollowTarget, KeyCode::F),
+            (Action::BuildTomato, KeyCode::Key1),
+            (Action::BuildPotato, KeyCode::Key2),
+            (Action::BuildCabbage, KeyCode::Key3),
+            (Action::BuildPeaShooter, KeyCode::Key4),
+            (Action::BuildLaserCarrot, KeyCode::Key5),
+            (Action::BuildCompost, KeyCode::Key6),
+            (Action::BuildPumpkinPatch, KeyCode::Key7),
+            (Action::Upgrade, KeyCode::U),
+            (Action::Sell, KeyCode::S),
+            (Action::CycleSelection, KeyCode::Tab),
+            (Action::CallWave, KeyCode::Space),
+            (Action::ExitGame, KeyCode::Escape),
+            (Action::ToggleInspector, KeyCode::T),
+        ];
+        Self {
+            keys: keys.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_actions_get_their_default_key() {
+        let mut saved = InputBindings::default();
+        saved.keys.remove(&Action::CycleSelection);
+
+        let bindings = saved.with_defaults();
+        assert_eq!(bindings.key(Action::CycleSelection), Some(KeyCode::Tab));
+    }
+
+    #[test]
+    fn defaults_never_steal_a_key_the_player_bound() {
+        let mut saved = InputBindings::default();
+        saved.keys.remove(&Action::CycleSelection);
+        saved.bind(Action::Upgrade, KeyCode::Tab);
+
+        let bindings = saved.with_defaults();
+        assert_eq!(bindings.key(Action::Upgrade), Some(KeyCode::Tab));
+        assert_eq!(bindings.key(Action::CycleSelection), None);
+    }
+
+    #[test]
+    fn saved_rebindings_survive_a_round_trip() {
+        let mut saved = InputBindings::default();
+        saved.bind(Action::Sell, KeyCode::X);
+
+        let text = ron::to_string(&saved).unwrap();
+        let bindings = ron::from_str::<InputBindings>(&text)
+            .unwrap()
+            .with_defaults();
+        assert_eq!(bindings.key(Action::Sell), Some(KeyCode::X));
+        assert_eq!(bindings.keys.len(), Action::ALL.len());
+    }
+
+    #[test]
+    fn binding_a_used_key_unbinds_the_old_action() {
+        let mut bindings = InputBindings::default();
+        bindings.bind(Action::Sell, KeyCode::U);
+
+        assert_eq!(bindings.key(Action::Sell), Some(KeyCode::U));
+        assert_eq!(bindings.key(Action::Upgrade), None);
+    }
+}
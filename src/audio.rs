@@ -0,0 +1,41 @@
+mod events;
+mod music;
+mod resources;
+mod systems;
+
+use bevy::prelude::*;
+pub use events::*;
+use music::*;
+pub use music::{MusicAssets, MusicManifest, MusicMood};
+pub use resources::*;
+use systems::*;
+
+use crate::GameState;
+
+pub struct GameAudioPlugin;
+
+impl Plugin for GameAudioPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SfxVolume>()
+            .init_resource::<MusicVolume>()
+            .init_resource::<SfxLimiter>()
+            .init_resource::<MusicPlayer>()
+            .insert_resource(MusicManifest::load())
+            .add_event::<SfxEvent>()
+            .add_startup_system(load_sfx_assets.in_base_set(StartupSet::PreStartup))
+            .add_startup_system(load_music_assets.in_base_set(StartupSet::PreStartup))
+            .add_systems(
+                (
+                    tower_fire_sfx,
+                    bullet_hit_sfx,
+                    enemy_death_sfx,
+                    wave_start_sfx,
+                )
+                    .in_set(OnUpdate(GameState::Gameplay))
+                    .before(play_sfx),
+            )
+            .add_system(ui_click_sfx.before(play_sfx))
+            .add_system(play_sfx.in_base_set(CoreSet::PostUpdate))
+            .add_systems((pick_music_mood, switch_music, crossfade_music).chain());
+    }
+}
@@ -0,0 +1,38 @@
+use crate::TowerType;
+
+/// A sound effect to play, see `SfxAssets` for the file behind each one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SfxEvent {
+    TowerFire(TowerType),
+    Hit,
+    EnemyDeath,
+    WaveStart,
+    Build,
+    Sell,
+    UiClick,
+    LifeLost,
+    GameOver,
+}
+
+impl SfxEvent {
+    /// Minimum seconds between two plays of the same effect, so a wall of
+    /// towers firing together doesn't stack into one clipping blast.
+    pub fn cooldown(&self) -> f64 {
+        match self {
+            SfxEvent::TowerFire(_) => 0.06,
+            SfxEvent::Hit => 0.05,
+            SfxEvent::EnemyDeath => 0.08,
+            _ => 0.0,
+        }
+    }
+
+    /// Relative loudness, frequent effects sit below the rare ones.
+    pub fn volume(&self) -> f32 {
+        match self {
+            SfxEvent::TowerFire(_) => 0.4,
+            SfxEvent::Hit => 0.5,
+            SfxEvent::UiClick => 0.6,
+            _ => 1.0,
+        }
+    }
+}
@@ -0,0 +1,261 @@
+use bevy::{asset::FileAssetIo, prelude::*};
+use serde::Deserialize;
+
+use crate::*;
+
+const MUSIC_MANIFEST: &str = "music.ron";
+const CROSSFADE_SECONDS: f32 = 1.5;
+
+/// Which kind of track fits what's happening on screen.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MusicMood {
+    Menu,
+    Gameplay,
+    Boss,
+    GameOver,
+}
+
+#[derive(Deserialize)]
+pub struct LevelMusic {
+    pub gameplay: String,
+    pub boss: String,
+}
+
+/// Track list read from `assets/music.ron`.
+#[derive(Resource, Deserialize)]
+pub struct MusicManifest {
+    pub menu: String,
+    pub game_over: String,
+    pub levels: Vec<LevelMusic>,
+}
+
+/// Every track in the manifest, loaded up front so a missing file is caught
+/// on the loading screen rather than when it's first played.
+#[derive(Resource)]
+pub struct MusicAssets {
+    pub tracks: Vec<Handle<AudioSource>>,
+}
+
+impl MusicAssets {
+    pub fn file_handles(&self) -> Vec<HandleUntyped> {
+        self.tracks
+            .iter()
+            .map(|track| track.clone_untyped())
+            .collect()
+    }
+}
+
+impl Default for MusicManifest {
+    fn default() -> Self {
+        Self {
+            menu: "music/menu.wav".to_string(),
+            game_over: "music/game_over.wav".to_string(),
+            levels: vec![LevelMusic {
+                gameplay: "music/gameplay.wav".to_string(),
+                boss: "music/boss.wav".to_string(),
+            }],
+        }
+    }
+}
+
+impl MusicManifest {
+    pub fn load() -> Self {
+        let path = FileAssetIo::get_base_path()
+            .join("assets")
+            .join(MUSIC_MANIFEST);
+        let manifest = std::fs::read_to_string(&path)
+            .map_err(|err| err.to_string())
+            .and_then(|contents| ron::from_str(&contents).map_err(|err| err.to_string()));
+
+        match manifest {
+            Ok(manifest) => manifest,
+            Err(err) => {
+                warn!(
+                    "Using default music, couldn't read {}: {}",
+                    path.display(),
+                    err
+                );
+                Self::default()
+            }
+        }
+    }
+
+    /// Every track path, in no particular order.
+    pub fn paths(&self) -> impl Iterator<Item = &str> {
+        [self.menu.as_str(), self.game_over.as_str()]
+            .into_iter()
+            .chain(
+                self.levels
+                    .iter()
+                    .flat_map(|level| [level.gameplay.as_str(), level.boss.as_str()]),
+            )
+    }
+
+    pub fn track(&self, mood: MusicMood, level: usize) -> Option<&str> {
+        let level = self.levels.get(level).or(self.levels.last());
+        match mood {
+            MusicMood::Menu => Some(&self.menu),
+            MusicMood::GameOver => Some(&self.game_over),
+            MusicMood::Gameplay => level.map(|level| level.gameplay.as_str()),
+            MusicMood::Boss => level.map(|level| level.boss.as_str()),
+        }
+    }
+}
+
+pub(super) fn load_music_assets(
+    mut commands: Commands,
+    manifest: Res<MusicManifest>,
+    assets: Res<AssetServer>,
+) {
+    commands.insert_resource(MusicAssets {
+        tracks: manifest.paths().map(|path| assets.load(path)).collect(),
+    });
+}
+
+struct MusicTrack {
+    sink: Handle<AudioSink>,
+    /// 0 is silent, 1 is full music volume
+    fade: f32,
+    fading_in: bool,
+}
+
+#[derive(Resource, Default)]
+pub struct MusicPlayer {
+    wanted: Option<MusicMood>,
+    playing: Option<MusicMood>,
+    tracks: Vec<MusicTrack>,
+}
+
+fn current_mood(
+    state: GameState,
+    wave_state: Option<&WaveState>,
+    waves: &WaveList,
+    player: Option<&Player>,
+) -> Option<MusicMood> {
+    match state {
+        GameState::Loading => None,
+        GameState::MainMenu | GameState::Settings | GameState::Controls => Some(MusicMood::Menu),
+        GameState::Gameplay => {
+            if player.is_some_and(|player| player.health == 0) {
+                return Some(MusicMood::GameOver);
+            }
+
+            let boss_wave = wave_state.is_some_and(|wave_state| {
+                matches!(
+                    wave_state.phase,
+                    WavePhase::Spawning { .. } | WavePhase::Clearing
+                ) && waves
+                    .waves
+                    .get(wave_state.current)
+                    .is_some_and(|wave| wave.has_boss())
+            });
+
+            Some(if boss_wave {
+                MusicMood::Boss
+            } else {
+                MusicMood::Gameplay
+            })
+        }
+    }
+}
+
+pub(super) fn pick_music_mood(
+    mut music: ResMut<MusicPlayer>,
+    state: Res<State<GameState>>,
+    wave_state: Option<Res<WaveState>>,
+    waves: Res<WaveList>,
+    player: Query<&Player>,
+) {
+    let mood = current_mood(
+        state.0,
+        wave_state.as_deref(),
+        &waves,
+        player.get_single().ok(),
+    );
+    if mood != music.wanted {
+        music.wanted = mood;
+    }
+}
+
+/// Starts a new track fading in, and fades out the rest, whenever the mood changes.
+pub(super) fn switch_music(
+    mut music: ResMut<MusicPlayer>,
+    manifest: Res<MusicManifest>,
+    level: Res<CurrentLevel>,
+    asset_server: Res<AssetServer>,
+    audio: Res<Audio>,
+    sinks: Res<Assets<AudioSink>>,
+) {
+    if music.wanted == music.playing {
+        return;
+    }
+    music.playing = music.wanted;
+
+    for track in &mut music.tracks {
+        track.fading_in = false;
+    }
+
+    let Some(path) = music.playing.and_then(|mood| manifest.track(mood, level.0)) else {
+        return;
+    };
+    let sink = audio.play_with_settings(
+        asset_server.load(path),
+        PlaybackSettings::LOOP.with_volume(0.0),
+    );
+    music.tracks.push(MusicTrack {
+        sink: sinks.get_handle(sink),
+        fade: 0.0,
+        fading_in: true,
+    });
+}
+
+pub(super) fn crossfade_music(
+    mut music: ResMut<MusicPlayer>,
+    volume: Res<MusicVolume>,
+    sinks: Res<Assets<AudioSink>>,
+    time: Res<Time>,
+) {
+    // Real time, menus and game speed shouldn't change how long a fade takes
+    let step = time.raw_delta_seconds() / CROSSFADE_SECONDS;
+
+    music.tracks.retain_mut(|track| {
+        track.fade = if track.fading_in {
+            (track.fade + step).min(1.0)
+        } else {
+            (track.fade - step).max(0.0)
+        };
+
+        // The sink only exists once the audio output has picked the sound up
+        let Some(sink) = sinks.get(&track.sink) else {
+            return true;
+        };
+        sink.set_volume(track.fade * volume.0);
+
+        if !track.fading_in && track.fade <= 0.0 {
+            sink.stop();
+            return false;
+        }
+        true
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn paths_cover_every_track_in_the_manifest() {
+        let manifest = MusicManifest::default();
+        let mut paths: Vec<_> = manifest.paths().collect();
+        paths.sort_unstable();
+        assert_eq!(
+            paths,
+            [
+                "music/boss.wav",
+                "music/game_over.wav",
+                "music/gameplay.wav",
+                "music/menu.wav"
+            ]
+        );
+    }
+}
@@ -0,0 +1,100 @@
+use bevy::{prelude::*, utils::HashMap};
+
+use crate::TowerType;
+
+use super::events::SfxEvent;
+
+/// Most effects started in one frame, anything past this is dropped.
+pub const MAX_SFX_PER_FRAME: usize = 6;
+
+/// Gain applied to every sound effect, derived from `Settings`.
+#[derive(Resource)]
+pub struct SfxVolume(pub f32);
+
+impl Default for SfxVolume {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
+/// Gain applied to music, derived from `Settings`.
+#[derive(Resource)]
+pub struct MusicVolume(pub f32);
+
+impl Default for MusicVolume {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
+#[derive(Resource)]
+pub struct SfxAssets {
+    pub tomato_fire: Handle<AudioSource>,
+    pub potato_fire: Handle<AudioSource>,
+    pub cabbage_fire: Handle<AudioSource>,
+    pub pea_fire: Handle<AudioSource>,
+    pub laser: Handle<AudioSource>,
+    pub hit: Handle<AudioSource>,
+    pub enemy_death: Handle<AudioSource>,
+    pub wave_start: Handle<AudioSource>,
+    pub build: Handle<AudioSource>,
+    pub sell: Handle<AudioSource>,
+    pub ui_click: Handle<AudioSource>,
+    pub life_lost: Handle<AudioSource>,
+    pub game_over: Handle<AudioSource>,
+}
+
+impl SfxAssets {
+    /// The effect for `event`, `None` when there's nothing to play.
+    pub fn sound(&self, event: SfxEvent) -> Option<Handle<AudioSource>> {
+        let sound = match event {
+            SfxEvent::TowerFire(tower_type) => return self.fire_sound(tower_type),
+            SfxEvent::Hit => &self.hit,
+            SfxEvent::EnemyDeath => &self.enemy_death,
+            SfxEvent::WaveStart => &self.wave_start,
+            SfxEvent::Build => &self.build,
+            SfxEvent::Sell => &self.sell,
+            SfxEvent::UiClick => &self.ui_click,
+            SfxEvent::LifeLost => &self.life_lost,
+            SfxEvent::GameOver => &self.game_over,
+        };
+        Some(sound.clone())
+    }
+
+    fn fire_sound(&self, tower_type: TowerType) -> Option<Handle<AudioSource>> {
+        let sound = match tower_type {
+            TowerType::Tomato => &self.tomato_fire,
+            TowerType::Potato => &self.potato_fire,
+            TowerType::Cabbage => &self.cabbage_fire,
+            TowerType::PeaShooter => &self.pea_fire,
+            TowerType::LaserCarrot => &self.laser,
+            // Support and farm towers don't attack
+            TowerType::Compost | TowerType::PumpkinPatch => return None,
+        };
+        Some(sound.clone())
+    }
+
+    pub fn file_handles(&self) -> Vec<HandleUntyped> {
+        vec![
+            self.tomato_fire.clone_untyped(),
+            self.potato_fire.clone_untyped(),
+            self.cabbage_fire.clone_untyped(),
+            self.pea_fire.clone_untyped(),
+            self.laser.clone_untyped(),
+            self.hit.clone_untyped(),
+            self.enemy_death.clone_untyped(),
+            self.wave_start.clone_untyped(),
+            self.build.clone_untyped(),
+            self.sell.clone_untyped(),
+            self.ui_click.clone_untyped(),
+            self.life_lost.clone_untyped(),
+            self.game_over.clone_untyped(),
+        ]
+    }
+}
+
+/// Tracks when each effect last played to enforce `SfxEvent::cooldown`.
+#[derive(Resource, Default)]
+pub struct SfxLimiter {
+    pub last_played: HashMap<SfxEvent, f64>,
+}
@@ -0,0 +1,105 @@
+use bevy::prelude::*;
+
+use crate::*;
+
+pub(super) fn load_sfx_assets(mut commands: Commands, assets: Res<AssetServer>) {
+    commands.insert_resource(SfxAssets {
+        tomato_fire: assets.load("sfx/tomato_fire.wav"),
+        potato_fire: assets.load("sfx/potato_fire.wav"),
+        cabbage_fire: assets.load("sfx/cabbage_fire.wav"),
+        pea_fire: assets.load("sfx/pea_fire.wav"),
+        laser: assets.load("sfx/laser.wav"),
+        hit: assets.load("sfx/hit.wav"),
+        enemy_death: assets.load("sfx/enemy_death.wav"),
+        wave_start: assets.load("sfx/wave_start.wav"),
+        build: assets.load("sfx/build.wav"),
+        sell: assets.load("sfx/sell.wav"),
+        ui_click: assets.load("sfx/ui_click.wav"),
+        life_lost: assets.load("assets_damage.wav"),
+        game_over: assets.load("sfx/game_over.wav"),
+    });
+}
+
+pub(super) fn tower_fire_sfx(
+    mut fired_events: EventReader<TowerFiredEvent>,
+    mut sfx: EventWriter<SfxEvent>,
+) {
+    for event in fired_events.iter() {
+        sfx.send(SfxEvent::TowerFire(event.tower_type));
+    }
+}
+
+pub(super) fn bullet_hit_sfx(
+    mut hit_events: EventReader<BulletHitEvent>,
+    mut sfx: EventWriter<SfxEvent>,
+) {
+    // Lethal hits are covered by the death sound
+    for _ in hit_events.iter().filter(|event| !event.lethal) {
+        sfx.send(SfxEvent::Hit);
+    }
+}
+
+pub(super) fn enemy_death_sfx(
+    mut death_events: EventReader<TargetDeathEvent>,
+    mut sfx: EventWriter<SfxEvent>,
+) {
+    for _ in death_events.iter() {
+        sfx.send(SfxEvent::EnemyDeath);
+    }
+}
+
+pub(super) fn wave_start_sfx(
+    mut started_events: EventReader<WaveStartedEvent>,
+    mut sfx: EventWriter<SfxEvent>,
+) {
+    for _ in started_events.iter() {
+        sfx.send(SfxEvent::WaveStart);
+    }
+}
+
+pub(super) fn ui_click_sfx(
+    interactions: Query<&Interaction, (With<Button>, Changed<Interaction>)>,
+    mut sfx: EventWriter<SfxEvent>,
+) {
+    for interaction in &interactions {
+        if matches!(interaction, Interaction::Clicked) {
+            sfx.send(SfxEvent::UiClick);
+        }
+    }
+}
+
+pub(super) fn play_sfx(
+    mut sfx_events: EventReader<SfxEvent>,
+    mut limiter: ResMut<SfxLimiter>,
+    sounds: Res<SfxAssets>,
+    volume: Res<SfxVolume>,
+    audio: Res<Audio>,
+    time: Res<Time>,
+) {
+    // Real time, so the limiter behaves the same at any game speed
+    let now = time.raw_elapsed_seconds_f64();
+    let mut played = 0;
+
+    for event in sfx_events.iter() {
+        // Keep reading so the overflow is dropped instead of played next frame
+        if played >= MAX_SFX_PER_FRAME {
+            continue;
+        }
+        let Some(sound) = sounds.sound(*event) else {
+            continue;
+        };
+        if let Some(last) = limiter.last_played.get(event) {
+            // Also collapses duplicates sent in the same frame
+            if now - last < event.cooldown().max(f64::EPSILON) {
+                continue;
+            }
+        }
+
+        limiter.last_played.insert(*event, now);
+        audio.play_with_settings(
+            sound,
+            PlaybackSettings::ONCE.with_volume(volume.0 * event.volume()),
+        );
+        played += 1;
+    }
+}
@@ -1,20 +1,36 @@
 mod components;
+mod events;
+mod resources;
 mod systems;
 
 use bevy::prelude::*;
 pub use components::*;
+pub use events::*;
+pub use resources::*;
 use systems::*;
 
-use crate::GameState;
+use crate::{tower_shooting, GameState};
 
 pub struct BulletPlugin;
 
 impl Plugin for BulletPlugin {
     fn build(&self, app: &mut App) {
         app.register_type::<Bullet>()
-            .register_type::<Lifetime>()
+            .register_type::<BulletPoolMetrics>()
+            .init_resource::<BulletPool>()
+            .init_resource::<BulletPoolMetrics>()
+            .add_event::<BulletHitEvent>()
+            .add_event::<BulletMissEvent>()
             .add_systems(
-                (bullet_collision, move_bullets, bullet_despawn)
+                (
+                    fire_bullets.after(tower_shooting),
+                    move_bullets,
+                    bullet_collision,
+                    cull_bullets,
+                    release_bullets,
+                    update_pool_metrics,
+                )
+                    .chain()
                     .in_set(OnUpdate(GameState::Gameplay)),
             )
             .add_system(despawn_bullets.in_schedule(OnExit(GameState::Gameplay)));
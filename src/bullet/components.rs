@@ -1,14 +1,76 @@
 use bevy::prelude::*;
+use rand::Rng;
 
-#[derive(Component, Default, Reflect)]
-#[reflect(Component)]
-pub struct Lifetime {
-    pub timer: Timer,
-}
+use crate::TowerType;
+
+/// Bullet entity owned by `BulletPool`, keyed by the tower type that fires it.
+/// Idle bullets are hidden and have no `Bullet` component.
+#[derive(Component)]
+pub struct PooledBullet(pub TowerType);
 
-#[derive(Component, Default, Reflect)]
+#[derive(Component, Reflect)]
 #[reflect(Component)]
 pub struct Bullet {
+    /// Tower that fired this bullet, may have been sold since
+    pub owner: Entity,
     pub direction: Vec3,
     pub speed: f32,
+    pub damage: i32,
+    pub damage_type: DamageType,
+    pub crit_chance: f32,
+    /// How far the bullet may fly before it counts as a miss
+    pub max_distance: f32,
+    pub traveled: f32,
+    /// Set once the bullet has hit or missed, it goes back to the pool at the end of the frame
+    pub spent: bool,
+}
+
+pub const CRIT_MULTIPLIER: i32 = 2;
+
+impl Bullet {
+    /// Damage dealt by a hit from this bullet, and whether it crit.
+    pub fn roll_damage(&self, rng: &mut impl Rng) -> (i32, bool) {
+        let crit = rng.gen::<f32>() < self.crit_chance;
+        if crit {
+            (self.damage * CRIT_MULTIPLIER, true)
+        } else {
+            (self.damage, false)
+        }
+    }
+}
+
+impl Default for Bullet {
+    fn default() -> Self {
+        Self {
+            owner: Entity::PLACEHOLDER,
+            direction: Vec3::ZERO,
+            speed: 0.0,
+            damage: 0,
+            damage_type: DamageType::default(),
+            crit_chance: 0.0,
+            max_distance: 0.0,
+            traveled: 0.0,
+            spent: false,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect, FromReflect)]
+pub enum DamageType {
+    #[default]
+    Physical,
+    Acid,
+    Nature,
+    Energy,
+}
+
+impl DamageType {
+    pub fn color(&self) -> Color {
+        match self {
+            DamageType::Physical => Color::WHITE,
+            DamageType::Acid => Color::ORANGE_RED,
+            DamageType::Nature => Color::LIME_GREEN,
+            DamageType::Energy => Color::ORANGE,
+        }
+    }
 }
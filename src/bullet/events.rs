@@ -0,0 +1,29 @@
+use bevy::prelude::*;
+
+use super::components::DamageType;
+
+pub struct BulletHitEvent {
+    /// Tower that fired the bullet
+    pub source: Entity,
+    pub target: Entity,
+    pub position: Vec3,
+    pub damage: i32,
+    pub damage_type: DamageType,
+    pub crit: bool,
+    /// Whether this hit took the target from alive to dead
+    pub lethal: bool,
+}
+
+pub struct BulletMissEvent {
+    /// Tower that fired the bullet
+    pub source: Entity,
+    pub position: Vec3,
+    pub reason: MissReason,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MissReason {
+    OutOfRange,
+    Ground,
+    OutOfBounds,
+}
@@ -0,0 +1,68 @@
+use bevy::{prelude::*, utils::HashMap};
+
+use crate::TowerType;
+
+/// Idle bullet entities ready to be fired again.
+#[derive(Resource, Default)]
+pub struct BulletPool {
+    pub idle: HashMap<TowerType, Vec<Entity>>,
+}
+
+#[derive(Resource, Default, Reflect)]
+#[reflect(Resource)]
+pub struct BulletPoolMetrics {
+    /// Bullet entities spawned because the pool was empty
+    pub created: u32,
+    /// Shots that reused an idle bullet
+    pub reused: u32,
+    pub in_flight: u32,
+    pub idle: u32,
+}
+
+impl BulletPoolMetrics {
+    /// Pooled bullets are only despawned when leaving gameplay, so every one
+    /// ever created is still in the pool.
+    pub fn pool_size(&self) -> u32 {
+        self.created
+    }
+
+    /// Fraction of shots that didn't need a new entity.
+    pub fn reuse_rate(&self) -> f32 {
+        let shots = self.created + self.reused;
+        if shots == 0 {
+            return 0.0;
+        }
+        self.reused as f32 / shots as f32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pool_size_counts_every_created_bullet() {
+        let metrics = BulletPoolMetrics {
+            created: 12,
+            reused: 30,
+            in_flight: 5,
+            idle: 7,
+        };
+        assert_eq!(metrics.pool_size(), 12);
+    }
+
+    #[test]
+    fn reuse_rate_is_the_share_of_shots_without_a_new_entity() {
+        let metrics = BulletPoolMetrics {
+            created: 10,
+            reused: 30,
+            ..default()
+        };
+        assert_eq!(metrics.reuse_rate(), 0.75);
+    }
+
+    #[test]
+    fn reuse_rate_is_zero_before_any_shots() {
+        assert_eq!(BulletPoolMetrics::default().reuse_rate(), 0.0);
+    }
+}
@@ -1,41 +1,166 @@
 use crate::*;
 use bevy::prelude::*;
 
-pub(super) fn bullet_despawn(
+/// Extra distance past the tower's range before a bullet counts as a miss,
+/// so shots at targets on the edge of range still land
+const RANGE_MARGIN: f32 = 1.5;
+const GROUND_HEIGHT: f32 = 0.0;
+
+pub(super) fn fire_bullets(
+    mut commands: Commands,
+    mut fired_events: EventReader<TowerFiredEvent>,
+    mut pool: ResMut<BulletPool>,
+    mut metrics: ResMut<BulletPoolMetrics>,
+    assets: Res<GameAssets>,
+) {
+    for event in fired_events
+        .iter()
+        .filter(|event| event.tower_type.attack_kind() == AttackKind::Projectile)
+    {
+        let (scene, mut bullet) =
+            event
+                .tower_type
+                .get_bullet(event.tower, event.direction, event.damage, &assets);
+        bullet.max_distance = event.range + RANGE_MARGIN;
+        // Top level so bullets keep flying if their tower is sold or rotated
+        let transform = Transform::from_translation(event.position);
+
+        match pool.idle.entry(event.tower_type).or_default().pop() {
+            Some(entity) => {
+                metrics.reused += 1;
+                commands
+                    .entity(entity)
+                    .insert((bullet, transform, Visibility::Inherited));
+            }
+            None => {
+                metrics.created += 1;
+                commands.spawn((
+                    SceneBundle {
+                        scene,
+                        transform,
+                        ..default()
+                    },
+                    bullet,
+                    PooledBullet(event.tower_type),
+                    Name::new("Bullet"),
+                ));
+            }
+        }
+    }
+}
+
+/// Retires bullets that flew past their range, hit the ground or left the level.
+pub(super) fn cull_bullets(
+    mut bullets: Query<(&mut Bullet, &Transform)>,
+    bounds: Res<LevelBounds>,
+    mut miss_events: EventWriter<BulletMissEvent>,
+) {
+    for (mut bullet, transform) in &mut bullets {
+        if bullet.spent {
+            continue;
+        }
+
+        let position = transform.translation;
+        let reason = if bullet.traveled > bullet.max_distance {
+            MissReason::OutOfRange
+        } else if position.y <= GROUND_HEIGHT {
+            MissReason::Ground
+        } else if !bounds.contains(position) {
+            MissReason::OutOfBounds
+        } else {
+            continue;
+        };
+
+        bullet.spent = true;
+        miss_events.send(BulletMissEvent {
+            source: bullet.owner,
+            position,
+            reason,
+        });
+    }
+}
+
+/// Returns spent bullets to the pool.
+pub(super) fn release_bullets(
     mut commands: Commands,
-    mut bullets: Query<(Entity, &mut Lifetime)>,
-    time: Res<Time>,
+    bullets: Query<(Entity, &Bullet, &PooledBullet)>,
+    mut pool: ResMut<BulletPool>,
 ) {
-    for (entity, mut lifetime) in &mut bullets {
-        lifetime.timer.tick(time.delta());
-        if lifetime.timer.just_finished() {
-            commands.entity(entity).despawn_recursive();
+    for (entity, bullet, pooled) in &bullets {
+        if bullet.spent {
+            commands
+                .entity(entity)
+                .remove::<Bullet>()
+                .insert(Visibility::Hidden);
+            pool.idle.entry(pooled.0).or_default().push(entity);
         }
     }
 }
 
-pub(super) fn despawn_bullets(mut commands: Commands, bullets: Query<Entity, With<Bullet>>) {
+pub(super) fn update_pool_metrics(pool: Res<BulletPool>, mut metrics: ResMut<BulletPoolMetrics>) {
+    // Worked out from the pool rather than queried, since this frame's spawns
+    // and releases are still sitting in the command queue
+    metrics.idle = pool.idle.values().map(|idle| idle.len() as u32).sum();
+    metrics.in_flight = metrics.created.saturating_sub(metrics.idle);
+}
+
+pub(super) fn despawn_bullets(
+    mut commands: Commands,
+    bullets: Query<Entity, With<PooledBullet>>,
+    mut pool: ResMut<BulletPool>,
+    mut metrics: ResMut<BulletPoolMetrics>,
+) {
+    info!(
+        "Bullet pool: {} bullets, {} created, {} reused ({:.0}% reuse)",
+        metrics.pool_size(),
+        metrics.created,
+        metrics.reused,
+        metrics.reuse_rate() * 100.0
+    );
+
     for bullet_entity in bullets.iter() {
         commands.entity(bullet_entity).despawn_recursive();
     }
+    pool.idle.clear();
+    *metrics = BulletPoolMetrics::default();
 }
 
-pub(super) fn move_bullets(mut bullets: Query<(&Bullet, &mut Transform)>, time: Res<Time>) {
-    for (bullet, mut transform) in &mut bullets {
-        transform.translation += bullet.direction.normalize() * bullet.speed * time.delta_seconds();
+pub(super) fn move_bullets(mut bullets: Query<(&mut Bullet, &mut Transform)>, time: Res<Time>) {
+    for (mut bullet, mut transform) in &mut bullets {
+        let step = bullet.speed * time.delta_seconds();
+        transform.translation += bullet.direction.normalize() * step;
+        bullet.traveled += step;
     }
 }
 
 pub(super) fn bullet_collision(
-    mut commands: Commands,
-    bullets: Query<(Entity, &GlobalTransform), With<Bullet>>,
-    mut targets: Query<(&mut Health, &Transform), With<Target>>,
+    mut bullets: Query<(&mut Bullet, &Transform)>,
+    mut targets: Query<(Entity, &mut Health, Option<&mut Shield>, &Transform), With<Target>>,
+    mut hit_event_writer: EventWriter<BulletHitEvent>,
 ) {
-    for (bullet_entity, bullet_transform) in &bullets {
-        for (mut health, target_transform) in &mut targets {
-            if Vec3::distance(bullet_transform.translation(), target_transform.translation) < 0.5 {
-                commands.entity(bullet_entity).despawn_recursive();
-                health.value -= 1;
+    let mut rng = rand::thread_rng();
+
+    for (mut bullet, bullet_transform) in &mut bullets {
+        if bullet.spent {
+            continue;
+        }
+
+        for (target_entity, mut health, shield, target_transform) in &mut targets {
+            if Vec3::distance(bullet_transform.translation, target_transform.translation) < 0.5 {
+                bullet.spent = true;
+
+                let (damage, crit) = bullet.roll_damage(&mut rng);
+                let lethal = health.take_damage(shield.map(|shield| shield.into_inner()), damage);
+
+                hit_event_writer.send(BulletHitEvent {
+                    source: bullet.owner,
+                    target: target_entity,
+                    position: bullet_transform.translation,
+                    damage,
+                    damage_type: bullet.damage_type,
+                    crit,
+                    lethal,
+                });
                 break;
             }
         }
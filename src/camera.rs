@@ -0,0 +1,35 @@
+mod components;
+mod systems;
+
+use bevy::prelude::*;
+pub use components::*;
+use systems::*;
+
+use crate::GameState;
+
+pub struct CameraRigPlugin;
+
+impl Plugin for CameraRigPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<CameraRig>()
+            .add_startup_system(spawn_camera)
+            .add_systems(
+                (
+                    camera_controls,
+                    camera_edge_pan,
+                    camera_drag_pan,
+                    camera_zoom,
+                    toggle_follow_target,
+                    follow_target,
+                )
+                    .chain()
+                    .in_set(OnUpdate(GameState::Gameplay)),
+            )
+            // Keeps easing into the last requested view outside of gameplay too
+            .add_systems(
+                (apply_camera_rig, update_projection)
+                    .chain()
+                    .after(follow_target),
+            );
+    }
+}
@@ -0,0 +1,77 @@
+use bevy::prelude::*;
+
+/// Pitch used by the top-down view, just shy of straight down so `looking_at` keeps a stable up
+pub const TOP_DOWN_PITCH: f32 = std::f32::consts::FRAC_PI_2 - 0.02;
+
+/// Orbit camera that looks at `focus` from `distance` away.
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct CameraRig {
+    /// Point on the ground the camera orbits around
+    pub focus: Vec3,
+    /// Rotation around the Y axis, in radians
+    pub yaw: f32,
+    /// Angle above the ground, in radians
+    pub pitch: f32,
+    pub distance: f32,
+    pub min_distance: f32,
+    pub max_distance: f32,
+    /// Width in pixels of the screen border that pans the camera
+    pub edge_pan_margin: f32,
+    /// Looks straight down with an orthographic projection
+    pub top_down: bool,
+    /// Target whose position the focus tracks
+    pub follow: Option<Entity>,
+    /// How quickly the camera catches up with the rig; higher is snappier
+    pub smoothing: f32,
+}
+
+impl Default for CameraRig {
+    fn default() -> Self {
+        Self {
+            focus: Vec3::ZERO,
+            yaw: -0.38,
+            pitch: 0.43,
+            distance: 6.0,
+            min_distance: 3.0,
+            max_distance: 30.0,
+            edge_pan_margin: 12.0,
+            top_down: false,
+            follow: None,
+            smoothing: 10.0,
+        }
+    }
+}
+
+impl CameraRig {
+    pub fn effective_pitch(&self) -> f32 {
+        if self.top_down {
+            TOP_DOWN_PITCH
+        } else {
+            self.pitch
+        }
+    }
+
+    /// Where the camera should end up; `apply_camera_rig` eases towards it.
+    pub fn transform(&self) -> Transform {
+        let pitch = self.effective_pitch();
+        let offset = Vec3::new(
+            self.yaw.sin() * pitch.cos(),
+            pitch.sin(),
+            self.yaw.cos() * pitch.cos(),
+        ) * self.distance;
+        Transform::from_translation(self.focus + offset).looking_at(self.focus, Vec3::Y)
+    }
+
+    /// Ground-plane forward and left vectors relative to the current view.
+    pub fn ground_axes(&self) -> (Vec3, Vec3) {
+        let forward = -Vec3::new(self.yaw.sin(), 0.0, self.yaw.cos());
+        let left = Vec3::new(forward.z, 0.0, -forward.x);
+        (forward, left)
+    }
+
+    /// Pan speed grows with zoom so the screen scrolls at a similar rate.
+    pub fn pan_speed(&self) -> f32 {
+        self.distance * 0.5
+    }
+}
@@ -0,0 +1,260 @@
+use bevy::{
+    input::mouse::{MouseMotion, MouseScrollUnit, MouseWheel},
+    prelude::*,
+    render::camera::ScalingMode,
+    utils::FloatOrd,
+    window::PrimaryWindow,
+};
+use bevy_mod_picking::*;
+
+use crate::*;
+
+const ROTATE_SPEED: f32 = 1.2;
+const ZOOM_STEP: f32 = 0.1;
+/// Pixel-based scrolling (touchpads) reports much larger values than line-based wheels
+const PIXELS_PER_LINE: f32 = 40.0;
+
+pub(super) fn spawn_camera(mut commands: Commands) {
+    let rig = CameraRig::default();
+    commands.spawn((
+        Camera3dBundle {
+            transform: rig.transform(),
+            projection: PerspectiveProjection::default().into(),
+            ..default()
+        },
+        PickingCameraBundle::default(),
+        rig,
+    ));
+}
+
+pub(super) fn camera_controls(
+    actions: ActionInput,
+    mut rigs: Query<&mut CameraRig>,
+    time: Res<Time>,
+) {
+    let Ok(mut rig) = rigs.get_single_mut() else {
+        return;
+    };
+
+    let (forward, left) = rig.ground_axes();
+    // Raw time so the camera isn't slowed down or sped up with the game
+    let step = rig.pan_speed() * time.raw_delta_seconds();
+    let focus = rig.focus;
+
+    if actions.pressed(Action::CameraForward) {
+        rig.focus += forward * step;
+    }
+    if actions.pressed(Action::CameraBack) {
+        rig.focus -= forward * step;
+    }
+    if actions.pressed(Action::CameraLeft) {
+        rig.focus += left * step;
+    }
+    if actions.pressed(Action::CameraRight) {
+        rig.focus -= left * step;
+    }
+    if actions.pressed(Action::CameraRotateLeft) {
+        rig.yaw += ROTATE_SPEED * time.raw_delta_seconds();
+    }
+    if actions.pressed(Action::CameraRotateRight) {
+        rig.yaw -= ROTATE_SPEED * time.raw_delta_seconds();
+    }
+
+    // Panning by hand takes over from follow mode
+    if rig.focus != focus {
+        rig.follow = None;
+    }
+    if actions.just_pressed(Action::ToggleTopDown) {
+        rig.top_down = !rig.top_down;
+    }
+}
+
+/// Follows the toughest target on the field (so bosses win), then whoever is furthest along.
+pub(super) fn toggle_follow_target(
+    actions: ActionInput,
+    mut rigs: Query<&mut CameraRig>,
+    targets: Query<(Entity, &Target, &Health, &Transform)>,
+    path: Res<TargetPath>,
+) {
+    if !actions.just_pressed(Action::FollowTarget) {
+        return;
+    }
+    let Ok(mut rig) = rigs.get_single_mut() else {
+        return;
+    };
+
+    if rig.follow.is_some() {
+        rig.follow = None;
+        return;
+    }
+
+    rig.follow = targets
+        .iter()
+        .max_by_key(|(_, target, health, transform)| {
+            (
+                health.max,
+                FloatOrd(-path.remaining_distance(target.path_index, transform.translation)),
+            )
+        })
+        .map(|(entity, ..)| entity);
+}
+
+pub(super) fn follow_target(
+    mut rigs: Query<&mut CameraRig>,
+    targets: Query<&GlobalTransform, With<Target>>,
+) {
+    for mut rig in &mut rigs {
+        let Some(followed) = rig.follow else {
+            continue;
+        };
+
+        match targets.get(followed) {
+            Ok(transform) => {
+                let position = transform.translation();
+                rig.focus = Vec3::new(position.x, 0.0, position.z);
+            }
+            // The target died or reached the end
+            Err(_) => rig.follow = None,
+        }
+    }
+}
+
+pub(super) fn camera_edge_pan(
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut rigs: Query<&mut CameraRig>,
+    ui_interactions: Query<&Interaction, With<Node>>,
+    time: Res<Time>,
+) {
+    let (Ok(window), Ok(mut rig)) = (windows.get_single(), rigs.get_single_mut()) else {
+        return;
+    };
+    if !window.focused {
+        return;
+    }
+    // The build and wave bars sit on the edges, aiming at a button shouldn't scroll the map.
+    // Only UI nodes count, picking also puts `Interaction` on tower bases
+    if ui_interactions
+        .iter()
+        .any(|interaction| *interaction != Interaction::None)
+    {
+        return;
+    }
+    // Window cursor coordinates start at the bottom left
+    let Some(cursor) = window.cursor_position() else {
+        return;
+    };
+
+    let (forward, left) = rig.ground_axes();
+    let margin = rig.edge_pan_margin;
+    let mut direction = Vec3::ZERO;
+
+    if cursor.x < margin {
+        direction += left;
+    }
+    if cursor.x > window.width() - margin {
+        direction -= left;
+    }
+    if cursor.y < margin {
+        direction -= forward;
+    }
+    if cursor.y > window.height() - margin {
+        direction += forward;
+    }
+
+    if direction != Vec3::ZERO {
+        let step = rig.pan_speed() * time.raw_delta_seconds();
+        rig.focus += direction.normalize() * step;
+        rig.follow = None;
+    }
+}
+
+pub(super) fn camera_drag_pan(
+    mouse_buttons: Res<Input<MouseButton>>,
+    mut motion_events: EventReader<MouseMotion>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut rigs: Query<&mut CameraRig>,
+) {
+    let delta: Vec2 = motion_events.iter().map(|motion| motion.delta).sum();
+    if !mouse_buttons.pressed(MouseButton::Middle) || delta == Vec2::ZERO {
+        return;
+    }
+    let (Ok(window), Ok(mut rig)) = (windows.get_single(), rigs.get_single_mut()) else {
+        return;
+    };
+
+    // Drag the ground under the cursor: a full window height moves roughly one view distance
+    let (forward, left) = rig.ground_axes();
+    let scale = rig.distance / window.height();
+    rig.focus += left * delta.x * scale + forward * delta.y * scale;
+    rig.follow = None;
+}
+
+pub(super) fn camera_zoom(
+    mut wheel_events: EventReader<MouseWheel>,
+    mut rigs: Query<&mut CameraRig>,
+) {
+    let scroll: f32 = wheel_events
+        .iter()
+        .map(|event| match event.unit {
+            MouseScrollUnit::Line => event.y,
+            MouseScrollUnit::Pixel => event.y / PIXELS_PER_LINE,
+        })
+        .sum();
+    if scroll == 0.0 {
+        return;
+    }
+    let Ok(mut rig) = rigs.get_single_mut() else {
+        return;
+    };
+
+    rig.distance =
+        (rig.distance * (1.0 - scroll * ZOOM_STEP)).clamp(rig.min_distance, rig.max_distance);
+}
+
+pub(super) fn apply_camera_rig(
+    mut cameras: Query<(&mut CameraRig, &mut Transform)>,
+    bounds: Res<LevelBounds>,
+    time: Res<Time>,
+) {
+    for (mut rig, mut transform) in &mut cameras {
+        let focus = bounds.clamp(rig.focus);
+        if focus != rig.focus {
+            rig.focus = focus;
+        }
+
+        // Frame-rate independent exponential ease towards the rig
+        let goal = rig.transform();
+        let t = 1.0 - (-rig.smoothing * time.raw_delta_seconds()).exp();
+        transform.translation = transform.translation.lerp(goal.translation, t);
+        transform.rotation = transform.rotation.slerp(goal.rotation, t);
+    }
+}
+
+/// Swaps to an orthographic projection once the camera has settled looking down, and back to
+/// perspective as soon as it leaves top-down mode.
+pub(super) fn update_projection(mut cameras: Query<(&CameraRig, &Transform, &mut Projection)>) {
+    for (rig, transform, mut projection) in &mut cameras {
+        let looking_down = transform.forward().y < -TOP_DOWN_PITCH.sin() + 0.01;
+
+        match (rig.top_down && looking_down, projection.as_mut()) {
+            (true, Projection::Perspective(perspective)) => {
+                let height = 2.0 * rig.distance * (perspective.fov / 2.0).tan();
+                *projection = OrthographicProjection {
+                    scaling_mode: ScalingMode::FixedVertical(height),
+                    ..default()
+                }
+                .into();
+            }
+            (true, Projection::Orthographic(orthographic)) => {
+                // Keep zoom working while orthographic
+                let fov = PerspectiveProjection::default().fov;
+                let height = 2.0 * rig.distance * (fov / 2.0).tan();
+                orthographic.scaling_mode = ScalingMode::FixedVertical(height);
+            }
+            (false, Projection::Orthographic(_)) => {
+                *projection = PerspectiveProjection::default().into();
+            }
+            (false, Projection::Perspective(_)) => {}
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use std::{fs, path::PathBuf};
+
+use bevy::prelude::*;
+use ron::ser::PrettyConfig;
+use serde::{de::DeserializeOwned, Serialize};
+
+const CONFIG_DIR_NAME: &str = "towerdefense";
+
+fn config_path(file_name: &str) -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join(CONFIG_DIR_NAME).join(file_name))
+}
+
+/// Reads `file_name` from the user's config dir. Missing or malformed files give `None`.
+pub fn load_config<T: DeserializeOwned>(file_name: &str) -> Option<T> {
+    let path = config_path(file_name)?;
+    let contents = fs::read_to_string(&path).ok()?;
+
+    match ron::from_str(&contents) {
+        Ok(value) => Some(value),
+        Err(err) => {
+            warn!("Ignoring malformed config {}: {}", path.display(), err);
+            None
+        }
+    }
+}
+
+pub fn save_config<T: Serialize>(file_name: &str, value: &T) {
+    let Some(path) = config_path(file_name) else {
+        warn!("No config directory available, not saving {}", file_name);
+        return;
+    };
+
+    let contents = match ron::ser::to_string_pretty(value, PrettyConfig::default()) {
+        Ok(contents) => contents,
+        Err(err) => {
+            error!("Failed to serialize {}: {}", file_name, err);
+            return;
+        }
+    };
+
+    if let Some(dir) = path.parent() {
+        if let Err(err) = fs::create_dir_all(dir) {
+            error!("Failed to create {}: {}", dir.display(), err);
+            return;
+        }
+    }
+    if let Err(err) = fs::write(&path, contents) {
+        error!("Failed to write {}: {}", path.display(), err);
+    }
+}
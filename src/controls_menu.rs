@@ -0,0 +1,268 @@
+use bevy::prelude::*;
+
+use crate::{Action, GameState, InputBindings, ScaledFont};
+
+pub struct ControlsMenuPlugin;
+
+impl Plugin for ControlsMenuPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<AwaitingRebind>()
+            .add_system(spawn_controls_menu.in_schedule(OnEnter(GameState::Controls)))
+            .add_system(despawn_controls_menu.in_schedule(OnExit(GameState::Controls)))
+            .add_systems(
+                (
+                    rebind_button_clicked,
+                    capture_rebind_key,
+                    reset_button_clicked,
+                    back_button_clicked,
+                    update_binding_labels,
+                )
+                    .in_set(OnUpdate(GameState::Controls)),
+            );
+    }
+}
+
+/// The action waiting for its next key press, if any.
+#[derive(Resource, Default)]
+pub struct AwaitingRebind(pub Option<Action>);
+
+#[derive(Component)]
+pub struct ControlsUIRoot;
+
+#[derive(Component)]
+pub struct RebindButton(pub Action);
+
+#[derive(Component)]
+pub struct ResetBindingsButton;
+
+#[derive(Component)]
+pub struct ControlsBackButton;
+
+pub(super) const ROW_STYLE: Style = Style {
+    size: Size::new(Val::Percent(40.0), Val::Px(28.0)),
+    align_self: AlignSelf::Center,
+    justify_content: JustifyContent::SpaceBetween,
+    align_items: AlignItems::Center,
+    margin: UiRect::all(Val::Px(1.0)),
+    ..Style::DEFAULT
+};
+
+pub(super) const KEY_BUTTON_STYLE: Style = Style {
+    size: Size::new(Val::Px(180.0), Val::Percent(100.0)),
+    justify_content: JustifyContent::Center,
+    align_items: AlignItems::Center,
+    ..Style::DEFAULT
+};
+
+pub(super) const FOOTER_BUTTON_STYLE: Style = Style {
+    size: Size::new(Val::Px(220.0), Val::Px(48.0)),
+    justify_content: JustifyContent::Center,
+    align_items: AlignItems::Center,
+    margin: UiRect::all(Val::Px(12.0)),
+    ..Style::DEFAULT
+};
+
+pub(super) fn spawn_controls_menu(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    bindings: Res<InputBindings>,
+) {
+    let font = asset_server.load("FiraSans-Bold.ttf");
+    let text_style = |font_size: f32| TextStyle {
+        font: font.clone(),
+        font_size,
+        color: Color::BEIGE,
+    };
+
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    size: Size::new(Val::Percent(100.0), Val::Percent(100.0)),
+                    justify_content: JustifyContent::Center,
+                    flex_direction: FlexDirection::Column,
+                    ..default()
+                },
+                ..default()
+            },
+            ControlsUIRoot,
+        ))
+        .with_children(|commands| {
+            commands.spawn((
+                TextBundle::from_section("Controls", text_style(64.0)).with_style(Style {
+                    align_self: AlignSelf::Center,
+                    margin: UiRect::all(Val::Px(12.0)),
+                    ..default()
+                }),
+                ScaledFont { base_size: 64.0 },
+            ));
+
+            for action in Action::ALL {
+                commands
+                    .spawn(NodeBundle {
+                        style: ROW_STYLE,
+                        ..default()
+                    })
+                    .with_children(|commands| {
+                        commands.spawn((
+                            TextBundle::from_section(action.name(), text_style(24.0)),
+                            ScaledFont { base_size: 24.0 },
+                        ));
+                        commands
+                            .spawn((
+                                ButtonBundle {
+                                    style: KEY_BUTTON_STYLE,
+                                    background_color: Color::DARK_GRAY.into(),
+                                    ..default()
+                                },
+                                RebindButton(action),
+                            ))
+                            .with_children(|commands| {
+                                commands.spawn((
+                                    TextBundle::from_section(
+                                        key_label(bindings.key(action)),
+                                        text_style(24.0),
+                                    ),
+                                    ScaledFont { base_size: 24.0 },
+                                ));
+                            });
+                    });
+            }
+
+            commands
+                .spawn(NodeBundle {
+                    style: Style {
+                        align_self: AlignSelf::Center,
+                        ..default()
+                    },
+                    ..default()
+                })
+                .with_children(|commands| {
+                    commands
+                        .spawn((
+                            ButtonBundle {
+                                style: FOOTER_BUTTON_STYLE,
+                                background_color: Color::BLUE.into(),
+                                ..default()
+                            },
+                            ResetBindingsButton,
+                        ))
+                        .with_children(|commands| {
+                            commands.spawn((
+                                TextBundle::from_section("Reset Defaults", text_style(28.0)),
+                                ScaledFont { base_size: 28.0 },
+                            ));
+                        });
+                    commands
+                        .spawn((
+                            ButtonBundle {
+                                style: FOOTER_BUTTON_STYLE,
+                                background_color: Color::RED.into(),
+                                ..default()
+                            },
+                            ControlsBackButton,
+                        ))
+                        .with_children(|commands| {
+                            commands.spawn((
+                                TextBundle::from_section("Back", text_style(28.0)),
+                                ScaledFont { base_size: 28.0 },
+                            ));
+                        });
+                });
+        });
+}
+
+pub(super) fn despawn_controls_menu(
+    mut commands: Commands,
+    menus: Query<Entity, With<ControlsUIRoot>>,
+    mut awaiting: ResMut<AwaitingRebind>,
+) {
+    awaiting.0 = None;
+    for menu_entity in menus.iter() {
+        commands.entity(menu_entity).despawn_recursive();
+    }
+}
+
+fn key_label(key: Option<KeyCode>) -> String {
+    match key {
+        Some(key) => format!("{:?}", key),
+        None => "Unbound".to_string(),
+    }
+}
+
+pub(super) fn rebind_button_clicked(
+    interactions: Query<(&Interaction, &RebindButton), Changed<Interaction>>,
+    mut awaiting: ResMut<AwaitingRebind>,
+) {
+    for (interaction, button) in &interactions {
+        if matches!(interaction, Interaction::Clicked) {
+            awaiting.0 = Some(button.0);
+        }
+    }
+}
+
+pub(super) fn capture_rebind_key(
+    keyboard_input: Res<Input<KeyCode>>,
+    mut awaiting: ResMut<AwaitingRebind>,
+    mut bindings: ResMut<InputBindings>,
+) {
+    let Some(action) = awaiting.0 else {
+        return;
+    };
+
+    if let Some(key) = keyboard_input.get_just_pressed().next() {
+        bindings.bind(action, *key);
+        bindings.save();
+        awaiting.0 = None;
+    }
+}
+
+pub(super) fn reset_button_clicked(
+    interactions: Query<&Interaction, (With<ResetBindingsButton>, Changed<Interaction>)>,
+    mut bindings: ResMut<InputBindings>,
+    mut awaiting: ResMut<AwaitingRebind>,
+) {
+    for interaction in &interactions {
+        if matches!(interaction, Interaction::Clicked) {
+            *bindings = InputBindings::default();
+            bindings.save();
+            awaiting.0 = None;
+        }
+    }
+}
+
+pub(super) fn back_button_clicked(
+    interactions: Query<&Interaction, (With<ControlsBackButton>, Changed<Interaction>)>,
+    mut game_state_next_state: ResMut<NextState<GameState>>,
+) {
+    for interaction in &interactions {
+        if matches!(interaction, Interaction::Clicked) {
+            game_state_next_state.set(GameState::MainMenu);
+        }
+    }
+}
+
+pub(super) fn update_binding_labels(
+    buttons: Query<(&RebindButton, &Children)>,
+    mut texts: Query<&mut Text>,
+    bindings: Res<InputBindings>,
+    awaiting: Res<AwaitingRebind>,
+) {
+    if !bindings.is_changed() && !awaiting.is_changed() {
+        return;
+    }
+
+    for (button, children) in &buttons {
+        let label = if awaiting.0 == Some(button.0) {
+            "Press a key...".to_string()
+        } else {
+            key_label(bindings.key(button.0))
+        };
+
+        for child in children.iter() {
+            if let Ok(mut text) = texts.get_mut(*child) {
+                text.sections[0].value = label.clone();
+            }
+        }
+    }
+}
@@ -0,0 +1,35 @@
+mod components;
+mod resources;
+mod systems;
+
+use bevy::prelude::*;
+pub use components::*;
+pub use resources::*;
+use systems::*;
+
+use crate::GameState;
+
+pub struct EconomyPlugin;
+
+impl Plugin for EconomyPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<EconomyRules>()
+            .init_resource::<IncomeBreakdown>()
+            .add_system(spawn_income_ui.in_schedule(OnEnter(GameState::Gameplay)))
+            .add_systems(
+                (
+                    // Starting a wave resets the breakdown, so it goes before this frame's income
+                    pay_early_call_bonus.before(give_money_on_kill),
+                    give_money_on_kill,
+                    pay_wave_clear_income.after(give_money_on_kill),
+                    // Interest is worked out on the bank before this wave's harvest is added
+                    pay_farm_harvests.after(pay_wave_clear_income),
+                    update_income_ui
+                        .after(pay_wave_clear_income)
+                        .after(pay_farm_harvests),
+                )
+                    .in_set(OnUpdate(GameState::Gameplay)),
+            )
+            .add_system(despawn_income_ui.in_schedule(OnExit(GameState::Gameplay)));
+    }
+}
@@ -0,0 +1,5 @@
+use bevy::prelude::*;
+
+/// End-of-wave income summary shown in the HUD.
+#[derive(Component)]
+pub struct IncomeUI;
@@ -0,0 +1,127 @@
+use bevy::prelude::*;
+
+/// Tunable income rules.
+#[derive(Resource)]
+pub struct EconomyRules {
+    pub kill_reward: u32,
+    pub wave_clear_bonus: u32,
+    /// Added to the clear bonus for every wave already cleared
+    pub wave_clear_bonus_growth: u32,
+    /// Fraction of banked money paid out when a wave is cleared
+    pub interest_rate: f32,
+    pub interest_cap: u32,
+    /// Paid per second of countdown skipped by calling a wave early
+    pub early_call_bonus_per_second: f32,
+    /// Indexed by `CurrentLevel`, the last entry covers any later levels
+    pub starting_money: Vec<u32>,
+}
+
+impl Default for EconomyRules {
+    fn default() -> Self {
+        Self {
+            kill_reward: 10,
+            wave_clear_bonus: 50,
+            wave_clear_bonus_growth: 10,
+            interest_rate: 0.1,
+            interest_cap: 50,
+            early_call_bonus_per_second: 2.0,
+            starting_money: vec![100, 150, 200],
+        }
+    }
+}
+
+impl EconomyRules {
+    pub fn starting_money(&self, level: usize) -> u32 {
+        self.starting_money
+            .get(level)
+            .or(self.starting_money.last())
+            .copied()
+            .unwrap_or_default()
+    }
+
+    pub fn clear_bonus(&self, wave: usize) -> u32 {
+        self.wave_clear_bonus + self.wave_clear_bonus_growth * wave as u32
+    }
+
+    pub fn interest(&self, money: u32) -> u32 {
+        ((money as f32 * self.interest_rate) as u32).min(self.interest_cap)
+    }
+
+    pub fn early_call_bonus(&self, skipped_seconds: f32) -> u32 {
+        (skipped_seconds * self.early_call_bonus_per_second) as u32
+    }
+}
+
+/// Where the money earned during the current wave came from.
+#[derive(Resource, Default)]
+pub struct IncomeBreakdown {
+    pub wave: usize,
+    pub kills: u32,
+    pub clear_bonus: u32,
+    pub interest: u32,
+    pub early_call: u32,
+    pub farms: u32,
+    /// Counts down while the summary is on screen
+    pub display: Timer,
+}
+
+impl IncomeBreakdown {
+    pub fn total(&self) -> u32 {
+        self.kills + self.clear_bonus + self.interest + self.early_call + self.farms
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interest_is_a_capped_fraction_of_the_bank() {
+        let rules = EconomyRules::default();
+        assert_eq!(rules.interest(0), 0);
+        assert_eq!(rules.interest(155), 15);
+        assert_eq!(rules.interest(10_000), rules.interest_cap);
+    }
+
+    #[test]
+    fn early_call_bonus_pays_per_whole_skipped_second() {
+        let rules = EconomyRules::default();
+        assert_eq!(rules.early_call_bonus(0.0), 0);
+        assert_eq!(rules.early_call_bonus(4.9), 9);
+        assert_eq!(rules.early_call_bonus(10.0), 20);
+    }
+
+    #[test]
+    fn clear_bonus_grows_each_wave() {
+        let rules = EconomyRules::default();
+        assert_eq!(rules.clear_bonus(0), 50);
+        assert_eq!(rules.clear_bonus(3), 80);
+    }
+
+    #[test]
+    fn later_levels_reuse_the_last_starting_money() {
+        let rules = EconomyRules::default();
+        assert_eq!(rules.starting_money(0), 100);
+        assert_eq!(rules.starting_money(2), 200);
+        assert_eq!(rules.starting_money(7), 200);
+
+        let empty = EconomyRules {
+            starting_money: Vec::new(),
+            ..default()
+        };
+        assert_eq!(empty.starting_money(0), 0);
+    }
+
+    #[test]
+    fn income_total_adds_every_source() {
+        let income = IncomeBreakdown {
+            kills: 30,
+            clear_bonus: 50,
+            interest: 12,
+            early_call: 8,
+            farms: 25,
+            ..default()
+        };
+        assert_eq!(income.total(), 125);
+    }
+}
@@ -0,0 +1,156 @@
+use bevy::prelude::*;
+
+use crate::*;
+
+const INCOME_DISPLAY_SECONDS: f32 = 6.0;
+
+pub(super) fn give_money_on_kill(
+    mut player: Query<&mut Player>,
+    mut death_events: EventReader<TargetDeathEvent>,
+    mut popup_events: EventWriter<PopupEvent>,
+    mut income: ResMut<IncomeBreakdown>,
+    rules: Res<EconomyRules>,
+) {
+    let mut player = player.single_mut();
+    for event in death_events.iter() {
+        player.money += rules.kill_reward;
+        income.kills += rules.kill_reward;
+        popup_events.send(PopupEvent::new(
+            event.position,
+            format!("+{}", rules.kill_reward),
+            Color::GOLD,
+        ));
+    }
+}
+
+pub(super) fn pay_early_call_bonus(
+    mut player: Query<&mut Player>,
+    mut started_events: EventReader<WaveStartedEvent>,
+    mut income: ResMut<IncomeBreakdown>,
+    rules: Res<EconomyRules>,
+) {
+    let mut player = player.single_mut();
+    for event in started_events.iter() {
+        let early_call = rules.early_call_bonus(event.skipped_seconds);
+        player.money += early_call;
+
+        *income = IncomeBreakdown {
+            wave: event.wave,
+            early_call,
+            ..default()
+        };
+    }
+}
+
+pub(super) fn pay_wave_clear_income(
+    mut player: Query<&mut Player>,
+    mut cleared_events: EventReader<WaveClearedEvent>,
+    mut income: ResMut<IncomeBreakdown>,
+    rules: Res<EconomyRules>,
+) {
+    let mut player = player.single_mut();
+    for event in cleared_events.iter() {
+        // Interest is worked out on what was banked before this wave's bonus
+        let interest = rules.interest(player.money);
+        let clear_bonus = rules.clear_bonus(event.wave);
+        player.money += interest + clear_bonus;
+
+        income.wave = event.wave;
+        income.interest = interest;
+        income.clear_bonus = clear_bonus;
+        income.display = Timer::from_seconds(INCOME_DISPLAY_SECONDS, TimerMode::Once);
+    }
+}
+
+/// Pays every farm tower's harvest when a wave is cleared.
+pub(super) fn pay_farm_harvests(
+    mut player: Query<&mut Player>,
+    mut cleared_events: EventReader<WaveClearedEvent>,
+    mut farms: Query<(&Tower, &Farm, &mut TowerRecord, &GlobalTransform)>,
+    mut popup_events: EventWriter<PopupEvent>,
+    mut income: ResMut<IncomeBreakdown>,
+) {
+    let mut player = player.single_mut();
+    for _ in cleared_events.iter() {
+        income.farms = 0;
+        for (tower, farm, mut record, transform) in &mut farms {
+            let harvest = farm.harvest_at(tower.level);
+            player.money += harvest;
+            record.harvested += harvest;
+            income.farms += harvest;
+            popup_events.send(PopupEvent::new(
+                transform.translation() + Vec3::Y,
+                format!("+{}", harvest),
+                Color::GOLD,
+            ));
+        }
+    }
+}
+
+pub(super) fn spawn_income_ui(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.spawn((
+        TextBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                position: UiRect {
+                    top: Val::Percent(12.0),
+                    left: Val::Percent(40.0),
+                    ..default()
+                },
+                ..default()
+            },
+            text: Text::from_section(
+                "",
+                TextStyle {
+                    font: asset_server.load("FiraSans-Bold.ttf"),
+                    font_size: 26.0,
+                    color: Color::GOLD,
+                },
+            )
+            .with_alignment(TextAlignment::Center),
+            visibility: Visibility::Hidden,
+            ..default()
+        },
+        IncomeUI,
+        ScaledFont { base_size: 26.0 },
+    ));
+}
+
+pub(super) fn update_income_ui(
+    mut income_ui: Query<(&mut Text, &mut Visibility), With<IncomeUI>>,
+    mut income: ResMut<IncomeBreakdown>,
+    time: Res<Time>,
+) {
+    let Ok((mut text, mut visibility)) = income_ui.get_single_mut() else {
+        return;
+    };
+
+    if income.display.tick(time.delta()).finished() {
+        *visibility = Visibility::Hidden;
+        return;
+    }
+
+    *visibility = Visibility::Inherited;
+    text.sections[0].value = format!(
+        "Wave {} cleared!\n\
+         Kills: +{}\n\
+         Clear bonus: +{}\n\
+         Interest: +{}\n\
+         Early call: +{}\n\
+         Farms: +{}\n\
+         Total: +{}",
+        income.wave + 1,
+        income.kills,
+        income.clear_bonus,
+        income.interest,
+        income.early_call,
+        income.farms,
+        income.total(),
+    );
+}
+
+pub(super) fn despawn_income_ui(mut commands: Commands, income_ui: Query<Entity, With<IncomeUI>>) {
+    for entity in income_ui.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
@@ -0,0 +1,159 @@
+use bevy::{asset::LoadState, prelude::*};
+
+use crate::{GameAssets, GameState, MusicAssets, ScaledFont, SfxAssets};
+
+pub struct LoadingPlugin;
+
+impl Plugin for LoadingPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system(spawn_loading_screen.in_schedule(OnEnter(GameState::Loading)))
+            .add_system(check_asset_loading.in_set(OnUpdate(GameState::Loading)))
+            .add_system(despawn_loading_screen.in_schedule(OnExit(GameState::Loading)));
+    }
+}
+
+#[derive(Component)]
+pub struct LoadingUIRoot;
+
+#[derive(Component)]
+pub struct LoadingText;
+
+#[derive(Component)]
+pub struct LoadingBarFill;
+
+pub(super) fn spawn_loading_screen(mut commands: Commands, assets: Res<GameAssets>) {
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                size: Size::new(Val::Percent(100.0), Val::Percent(100.0)),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                flex_direction: FlexDirection::Column,
+                ..default()
+            },
+            ..default()
+        })
+        .insert(LoadingUIRoot)
+        .with_children(|commands| {
+            commands.spawn((
+                TextBundle::from_section(
+                    "Loading...",
+                    TextStyle {
+                        font: assets.font.clone(),
+                        font_size: 40.0,
+                        color: Color::WHITE,
+                    },
+                )
+                .with_text_alignment(TextAlignment::Center),
+                LoadingText,
+                ScaledFont { base_size: 40.0 },
+            ));
+            commands
+                .spawn(NodeBundle {
+                    style: Style {
+                        size: Size::new(Val::Percent(40.0), Val::Px(16.0)),
+                        margin: UiRect::top(Val::Px(16.0)),
+                        ..default()
+                    },
+                    background_color: Color::rgb(0.1, 0.1, 0.1).into(),
+                    ..default()
+                })
+                .with_children(|commands| {
+                    commands.spawn((
+                        NodeBundle {
+                            style: Style {
+                                size: Size::new(Val::Percent(0.0), Val::Percent(100.0)),
+                                ..default()
+                            },
+                            background_color: Color::rgb(0.3, 0.5, 0.3).into(),
+                            ..default()
+                        },
+                        LoadingBarFill,
+                    ));
+                });
+        });
+}
+
+pub(super) fn check_asset_loading(
+    (assets, sfx_assets, music_assets): (Res<GameAssets>, Res<SfxAssets>, Res<MusicAssets>),
+    asset_server: Res<AssetServer>,
+    mut text: Query<&mut Text, With<LoadingText>>,
+    mut bar: Query<&mut Style, With<LoadingBarFill>>,
+    mut game_state_next_state: ResMut<NextState<GameState>>,
+    mut reported: Local<bool>,
+) {
+    let mut handles = assets.file_handles();
+    handles.extend(sfx_assets.file_handles());
+    handles.extend(music_assets.file_handles());
+    let mut loaded = 0;
+    let mut failed = Vec::new();
+    let mut dropped = Vec::new();
+
+    let path = |handle: &HandleUntyped| {
+        asset_server
+            .get_handle_path(handle)
+            .map(|path| path.path().display().to_string())
+            .unwrap_or_else(|| "<unknown>".to_string())
+    };
+    for handle in &handles {
+        match asset_server.get_load_state(handle) {
+            LoadState::Loaded => loaded += 1,
+            LoadState::Failed => failed.push(path(handle)),
+            // Every handle here is kept alive by a resource, so this is a bug rather than
+            // a missing file, and the asset will never finish loading
+            LoadState::Unloaded => dropped.push(path(handle)),
+            LoadState::NotLoaded | LoadState::Loading => {}
+        }
+    }
+
+    let progress = loaded as f32 / handles.len() as f32;
+    for mut style in &mut bar {
+        style.size.width = Val::Percent(progress * 100.0);
+    }
+
+    // Stay on this screen so a broken install is obvious instead of playing without models
+    if !failed.is_empty() || !dropped.is_empty() {
+        let mut message = Vec::new();
+        if !failed.is_empty() {
+            message.push(format!("Failed to load:\n{}", failed.join("\n")));
+        }
+        if !dropped.is_empty() {
+            message.push(format!("Unloaded before use:\n{}", dropped.join("\n")));
+        }
+
+        if !*reported {
+            if !failed.is_empty() {
+                error!("Failed to load assets: {}", failed.join(", "));
+            }
+            if !dropped.is_empty() {
+                error!(
+                    "Asset handles dropped while loading: {}",
+                    dropped.join(", ")
+                );
+            }
+            *reported = true;
+        }
+        for mut text in &mut text {
+            text.sections[0].value = message.join("\n\n");
+            text.sections[0].style.color = Color::ORANGE_RED;
+        }
+        return;
+    }
+
+    for mut text in &mut text {
+        text.sections[0].value = format!("Loading... {}/{}", loaded, handles.len());
+    }
+
+    if loaded == handles.len() {
+        game_state_next_state.set(GameState::MainMenu);
+    }
+}
+
+pub(super) fn despawn_loading_screen(
+    mut commands: Commands,
+    roots: Query<Entity, With<LoadingUIRoot>>,
+) {
+    for root in &roots {
+        commands.entity(root).despawn_recursive();
+    }
+}
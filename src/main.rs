@@ -1,23 +1,46 @@
+mod actions;
+mod audio;
 mod bullet;
+mod camera;
+mod config;
+mod controls_menu;
+mod economy;
+mod loading;
 mod main_menu;
+mod particles;
 mod player;
+mod popup;
+mod settings;
 mod target;
 mod tower;
+mod ui_scaling;
+mod wave;
 
+pub use actions::*;
+pub use audio::*;
 pub use bullet::*;
+pub use camera::*;
+pub use controls_menu::*;
+pub use economy::*;
+pub use loading::*;
 pub use main_menu::*;
+pub use particles::*;
 pub use player::*;
+pub use popup::*;
+pub use settings::*;
 pub use target::*;
 pub use tower::*;
+pub use ui_scaling::*;
+pub use wave::*;
 
-use bevy::{app::AppExit, pbr::NotShadowCaster, prelude::*, window::WindowResolution};
+use bevy::{app::AppExit, prelude::*, window::WindowResolution};
 use bevy_inspector_egui::quick::WorldInspectorPlugin;
 use bevy_mod_picking::*;
 
-pub const WINDOW_HEIGHT: f32 = 720.0;
-pub const WINDOW_WIDTH: f32 = 1280.0;
-
 fn main() {
+    let settings = Settings::load();
+    let [window_width, window_height] = settings.resolution;
+
     App::new()
         // Toggle Egui Inspector State
         .add_state::<InspectorState>()
@@ -27,29 +50,46 @@ fn main() {
         .insert_resource(ClearColor(Color::rgb(0.2, 0.2, 0.2)))
         .add_plugins(DefaultPlugins.set(WindowPlugin {
             primary_window: Some(Window {
-                resolution: WindowResolution::new(WINDOW_WIDTH, WINDOW_HEIGHT),
+                resolution: WindowResolution::new(window_width as f32, window_height as f32),
+                mode: settings.window_mode.window_mode(),
                 title: "Bevy Tower Defense".to_string(),
-                resizable: false,
+                resizable: true,
                 ..default()
             }),
             ..default()
         }))
+        .insert_resource(settings)
+        .init_resource::<CurrentLevel>()
+        .insert_resource(LevelBounds {
+            min: Vec2::splat(-25.0),
+            max: Vec2::splat(25.0),
+        })
         // Plugins
         .add_plugins(DefaultPickingPlugins)
+        .add_plugin(LoadingPlugin)
+        .add_plugin(ActionsPlugin)
+        .add_plugin(GameAudioPlugin)
         .add_plugin(PlayerPlugin)
         .add_plugin(WorldInspectorPlugin::new().run_if(in_state(InspectorState::On)))
         .add_plugin(MainMenuPlugin)
+        .add_plugin(ControlsMenuPlugin)
+        .add_plugin(SettingsPlugin)
+        .add_plugin(UiScalingPlugin)
+        .add_plugin(CameraRigPlugin)
         .add_plugin(TowerPlugin)
         .add_plugin(TargetPlugin)
         .add_plugin(BulletPlugin)
+        .add_plugin(PopupPlugin)
+        .add_plugin(ParticlePlugin)
+        .add_plugin(WavePlugin)
+        .add_plugin(EconomyPlugin)
         // Startup Systems
-        .add_startup_system(spawn_camera)
         .add_startup_system(asset_loading.in_base_set(StartupSet::PreStartup))
         // Systems
         .add_system(spawn_basic_scene.in_schedule(OnEnter(GameState::Gameplay)))
-        .add_system(camera_controls)
         .add_system(toggle_inspector_egui)
-        .add_system(exit_game)
+        // Escape has to reach the rebinding prompt instead of closing the game
+        .add_system(exit_game.run_if(not(in_state(GameState::Controls))))
         .run();
 }
 
@@ -57,7 +97,10 @@ fn main() {
 #[derive(States, Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
 pub enum GameState {
     #[default]
+    Loading,
     MainMenu,
+    Settings,
+    Controls,
     Gameplay,
 }
 
@@ -81,34 +124,103 @@ pub struct GameAssets {
     potato_scene: Handle<Scene>,
     cabbage_tower_scene: Handle<Scene>,
     cabbage_scene: Handle<Scene>,
+    pea_shooter_tower_scene: Handle<Scene>,
+    laser_carrot_tower_scene: Handle<Scene>,
+    compost_tower_scene: Handle<Scene>,
+    pumpkin_patch_tower_scene: Handle<Scene>,
     target_scene: Handle<Scene>,
+    font: Handle<Font>,
+    collider_mesh: Handle<Mesh>,
+    base_collider_color: Handle<StandardMaterial>,
+    tower_collider_color: Handle<StandardMaterial>,
+    selected_collider_color: Handle<StandardMaterial>,
+    beam_mesh: Handle<Mesh>,
+    beam_material: Handle<StandardMaterial>,
+    tracer_material: Handle<StandardMaterial>,
+}
+
+impl GameAssets {
+    /// Handles backed by files on disk, which the loading screen waits on.
+    pub fn file_handles(&self) -> Vec<HandleUntyped> {
+        vec![
+            self.tower_base_scene.clone_untyped(),
+            self.tomato_tower_scene.clone_untyped(),
+            self.tomato_scene.clone_untyped(),
+            self.potato_tower_scene.clone_untyped(),
+            self.potato_scene.clone_untyped(),
+            self.cabbage_tower_scene.clone_untyped(),
+            self.cabbage_scene.clone_untyped(),
+            self.target_scene.clone_untyped(),
+            self.font.clone_untyped(),
+        ]
+    }
+}
+
+/// Index of the level being played.
+#[derive(Resource, Default)]
+pub struct CurrentLevel(pub usize);
+
+/// Playable area on the XZ plane.
+#[derive(Resource)]
+pub struct LevelBounds {
+    pub min: Vec2,
+    pub max: Vec2,
+}
+
+impl LevelBounds {
+    pub fn size(&self) -> Vec2 {
+        self.max - self.min
+    }
+
+    /// Whether the XZ coordinates of `position` are inside the bounds.
+    pub fn contains(&self, position: Vec3) -> bool {
+        (self.min.x..=self.max.x).contains(&position.x)
+            && (self.min.y..=self.max.y).contains(&position.z)
+    }
+
+    /// Clamps the XZ coordinates of `position` into the bounds, leaving Y alone.
+    pub fn clamp(&self, position: Vec3) -> Vec3 {
+        Vec3::new(
+            position.x.clamp(self.min.x, self.max.x),
+            position.y,
+            position.z.clamp(self.min.y, self.max.y),
+        )
+    }
 }
 
 // === Game-level systems ===
 
-pub fn exit_game(
-    keyboard_input: Res<Input<KeyCode>>,
-    mut app_exit_event_writer: EventWriter<AppExit>,
-) {
-    if keyboard_input.just_pressed(KeyCode::Escape) {
+pub fn exit_game(actions: ActionInput, mut app_exit_event_writer: EventWriter<AppExit>) {
+    if actions.just_pressed(Action::ExitGame) {
         app_exit_event_writer.send(AppExit);
     }
 }
 
 pub fn toggle_inspector_egui(
-    keyboard_input: Res<Input<KeyCode>>,
+    actions: ActionInput,
     inspector_state: Res<State<InspectorState>>,
     mut inspector_state_next_state: ResMut<NextState<InspectorState>>,
 ) {
-    if keyboard_input.just_pressed(KeyCode::T) && inspector_state.0 != InspectorState::On {
+    if actions.just_pressed(Action::ToggleInspector) && inspector_state.0 != InspectorState::On {
         inspector_state_next_state.set(InspectorState::On);
     }
-    if keyboard_input.just_pressed(KeyCode::T) && inspector_state.0 != InspectorState::Off {
+    if actions.just_pressed(Action::ToggleInspector) && inspector_state.0 != InspectorState::Off {
         inspector_state_next_state.set(InspectorState::Off);
     }
 }
 
-pub fn asset_loading(mut commands: Commands, assets: Res<AssetServer>) {
+pub fn asset_loading(
+    mut commands: Commands,
+    assets: Res<AssetServer>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut scenes: ResMut<Assets<Scene>>,
+) {
+    let pea_shooter_tower_scene = pea_shooter_scene(&mut meshes, &mut materials);
+    let laser_carrot_tower_scene = laser_carrot_scene(&mut meshes, &mut materials);
+    let compost_tower_scene = compost_scene(&mut meshes, &mut materials);
+    let pumpkin_patch_tower_scene = pumpkin_patch_scene(&mut meshes, &mut materials);
+
     commands.insert_resource(GameAssets {
         tower_base_scene: assets.load("TowerBase.glb#Scene0"),
         tomato_tower_scene: assets.load("TomatoTower.glb#Scene0"),
@@ -117,56 +229,31 @@ pub fn asset_loading(mut commands: Commands, assets: Res<AssetServer>) {
         potato_scene: assets.load("Potato.glb#Scene0"),
         cabbage_tower_scene: assets.load("CabbageTower.glb#Scene0"),
         cabbage_scene: assets.load("Cabbage.glb#Scene0"),
+        pea_shooter_tower_scene: scenes.add(pea_shooter_tower_scene),
+        laser_carrot_tower_scene: scenes.add(laser_carrot_tower_scene),
+        compost_tower_scene: scenes.add(compost_tower_scene),
+        pumpkin_patch_tower_scene: scenes.add(pumpkin_patch_tower_scene),
         target_scene: assets.load("Target.glb#Scene0"),
-    });
-}
-
-pub fn spawn_camera(mut commands: Commands) {
-    commands.spawn((
-        Camera3dBundle {
-            transform: Transform::from_xyz(-2.0, 2.5, 5.0).looking_at(Vec3::ZERO, Vec3::Y),
+        font: assets.load("FiraSans-Bold.ttf"),
+        collider_mesh: meshes.add(shape::Capsule::default().into()),
+        base_collider_color: materials.add(Color::rgba(0.3, 0.5, 0.3, 0.3).into()),
+        tower_collider_color: materials.add(Color::rgba(0.3, 0.5, 0.3, 0.0).into()),
+        selected_collider_color: materials.add(Color::rgba(0.3, 0.9, 0.3, 0.9).into()),
+        beam_mesh: meshes.add(shape::Box::new(1.0, 1.0, 1.0).into()),
+        beam_material: materials.add(StandardMaterial {
+            base_color: Color::rgba(1.0, 0.6, 0.15, 0.8),
+            emissive: Color::ORANGE_RED,
+            unlit: true,
+            alpha_mode: AlphaMode::Blend,
             ..default()
-        },
-        PickingCameraBundle::default(),
-    ));
-}
-
-pub fn camera_controls(
-    keyboard_input: Res<Input<KeyCode>>,
-    mut camera_query: Query<&mut Transform, With<Camera3d>>,
-    time: Res<Time>,
-) {
-    let mut camera = camera_query.single_mut();
-
-    let mut forward = camera.forward();
-    forward.y = 0.0;
-    forward = forward.normalize();
-
-    let mut left = camera.left();
-    left.y = 0.0;
-    left = left.normalize();
-
-    let speed: f32 = 3.0;
-    let rotate_speed: f32 = 0.3;
-
-    if keyboard_input.pressed(KeyCode::W) {
-        camera.translation += forward * time.delta_seconds() * speed;
-    }
-    if keyboard_input.pressed(KeyCode::S) {
-        camera.translation -= forward * time.delta_seconds() * speed;
-    }
-    if keyboard_input.pressed(KeyCode::A) {
-        camera.translation += left * time.delta_seconds() * speed;
-    }
-    if keyboard_input.pressed(KeyCode::D) {
-        camera.translation -= left * time.delta_seconds() * speed;
-    }
-    if keyboard_input.pressed(KeyCode::Q) {
-        camera.rotate_axis(Vec3::Y, rotate_speed * time.delta_seconds())
-    }
-    if keyboard_input.pressed(KeyCode::E) {
-        camera.rotate_axis(Vec3::Y, -rotate_speed * time.delta_seconds())
-    }
+        }),
+        tracer_material: materials.add(StandardMaterial {
+            base_color: Color::rgba(0.85, 1.0, 0.7, 0.9),
+            unlit: true,
+            alpha_mode: AlphaMode::Blend,
+            ..default()
+        }),
+    });
 }
 
 pub fn spawn_basic_scene(
@@ -174,65 +261,31 @@ pub fn spawn_basic_scene(
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
     game_assets: Res<GameAssets>,
+    bounds: Res<LevelBounds>,
 ) {
+    let ground_center = (bounds.min + bounds.max) / 2.0;
     commands
         .spawn(PbrBundle {
             mesh: meshes.add(Mesh::from(shape::Plane {
-                size: 50.0,
+                size: bounds.size().max_element(),
                 subdivisions: 0,
             })),
+            transform: Transform::from_xyz(ground_center.x, 0.0, ground_center.y),
             material: materials.add(Color::rgb(0.3, 0.5, 0.3).into()),
             ..default()
         })
         .insert(Name::new("Ground"));
 
-    let default_collider_color = materials.add(Color::rgba(0.3, 0.5, 0.3, 0.3).into());
-    let selected_collider_color = materials.add(Color::rgba(0.3, 0.9, 0.3, 0.9).into());
-
     for i in 0..10 {
         for j in 0..2 {
-            commands
-                .spawn(SpatialBundle::from_transform(Transform::from_xyz(
-                    2.0 * i as f32 + j as f32,
-                    0.8,
-                    5.0 * j as f32,
-                )))
-                .insert(Name::new("Tower_Base"))
-                .insert(meshes.add(shape::Capsule::default().into()))
-                .insert(Highlighting {
-                    initial: default_collider_color.clone(),
-                    hovered: Some(selected_collider_color.clone()),
-                    pressed: Some(selected_collider_color.clone()),
-                    selected: Some(selected_collider_color.clone()),
-                })
-                .insert(default_collider_color.clone())
-                .insert(NotShadowCaster)
-                .insert(PickableBundle::default())
-                .with_children(|commands| {
-                    commands.spawn(SceneBundle {
-                        scene: game_assets.tower_base_scene.clone(),
-                        transform: Transform::from_xyz(0.0, -0.8, 0.0),
-                        ..Default::default()
-                    });
-                });
+            spawn_tower_base(
+                &mut commands,
+                &game_assets,
+                Vec3::new(2.0 * i as f32 + j as f32, 0.8, 5.0 * j as f32),
+            );
         }
     }
 
-    for i in 1..25 {
-        commands
-            .spawn(SceneBundle {
-                scene: game_assets.target_scene.clone(),
-                transform: Transform::from_xyz(-2.0 * i as f32, 0.4, 2.5),
-                ..Default::default()
-            })
-            .insert(Target {
-                speed: 0.45,
-                ..Default::default()
-            })
-            .insert(Health { value: 3 })
-            .insert(Name::new("Target"));
-    }
-
     commands
         .spawn(PointLightBundle {
             point_light: PointLight {
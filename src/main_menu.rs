@@ -1,6 +1,6 @@
 use bevy::{app::AppExit, prelude::*};
 
-use crate::GameState;
+use crate::{GameState, ScaledFont};
 
 pub struct MainMenuPlugin;
 
@@ -9,7 +9,13 @@ impl Plugin for MainMenuPlugin {
         app.add_system(spawn_main_menu.in_schedule(OnEnter(GameState::MainMenu)))
             .add_system(despawn_main_menu.in_schedule(OnExit(GameState::MainMenu)))
             .add_systems(
-                (start_button_clicked, quit_button_clicked).in_set(OnUpdate(GameState::MainMenu)),
+                (
+                    start_button_clicked,
+                    settings_button_clicked,
+                    controls_button_clicked,
+                    quit_button_clicked,
+                )
+                    .in_set(OnUpdate(GameState::MainMenu)),
             );
     }
 }
@@ -20,6 +26,12 @@ pub struct MenuUIRoot;
 #[derive(Component)]
 pub struct StartButton;
 
+#[derive(Component)]
+pub struct SettingsButton;
+
+#[derive(Component)]
+pub struct ControlsButton;
+
 #[derive(Component)]
 pub struct QuitButton;
 
@@ -31,7 +43,9 @@ pub(super) const MAIN_MENU_STYLE: Style = Style {
 };
 
 pub(super) const BUTTON_STYLE: Style = Style {
-    size: Size::new(Val::Percent(65.0), Val::Percent(15.0)),
+    size: Size::new(Val::Percent(65.0), Val::Percent(12.0)),
+    min_size: Size::new(Val::Px(240.0), Val::Px(48.0)),
+    max_size: Size::new(Val::Px(900.0), Val::Px(140.0)),
     align_self: AlignSelf::Center,
     justify_content: JustifyContent::Center,
     margin: UiRect::all(Val::Percent(2.0)),
@@ -48,6 +62,12 @@ pub(super) fn spawn_main_menu(mut commands: Commands, asset_server: Res<AssetSer
     let start_button = spawn_button(&mut commands, &asset_server, "Start Game", Color::BLUE);
     commands.entity(start_button).insert(StartButton);
 
+    let settings_button = spawn_button(&mut commands, &asset_server, "Settings", Color::GRAY);
+    commands.entity(settings_button).insert(SettingsButton);
+
+    let controls_button = spawn_button(&mut commands, &asset_server, "Controls", Color::GRAY);
+    commands.entity(controls_button).insert(ControlsButton);
+
     let quit_button = spawn_button(&mut commands, &asset_server, "Quit", Color::RED);
     commands.entity(quit_button).insert(QuitButton);
 
@@ -60,24 +80,29 @@ pub(super) fn spawn_main_menu(mut commands: Commands, asset_server: Res<AssetSer
             MenuUIRoot,
         ))
         .with_children(|commands| {
-            commands.spawn((TextBundle {
-                style: Style {
-                    align_self: AlignSelf::Center,
-                    margin: UiRect::all(Val::Percent(3.0)),
+            commands.spawn((
+                TextBundle {
+                    style: Style {
+                        align_self: AlignSelf::Center,
+                        margin: UiRect::all(Val::Percent(3.0)),
+                        ..default()
+                    },
+                    text: Text::from_section(
+                        "Tower Defense Tutorial",
+                        TextStyle {
+                            font: asset_server.load("FiraSans-Bold.ttf"),
+                            font_size: 96.0,
+                            color: Color::BEIGE,
+                        },
+                    ),
                     ..default()
                 },
-                text: Text::from_section(
-                    "Tower Defense Tutorial",
-                    TextStyle {
-                        font: asset_server.load("FiraSans-Bold.ttf"),
-                        font_size: 96.0,
-                        color: Color::BEIGE,
-                    },
-                ),
-                ..default()
-            },));
+                ScaledFont { base_size: 96.0 },
+            ));
         })
         .add_child(start_button)
+        .add_child(settings_button)
+        .add_child(controls_button)
         .add_child(quit_button);
 }
 
@@ -102,6 +127,28 @@ pub(super) fn start_button_clicked(
     }
 }
 
+pub(super) fn settings_button_clicked(
+    interactions: Query<&Interaction, (With<SettingsButton>, Changed<Interaction>)>,
+    mut game_state_next_state: ResMut<NextState<GameState>>,
+) {
+    for interaction in &interactions {
+        if matches!(interaction, Interaction::Clicked) {
+            game_state_next_state.set(GameState::Settings);
+        }
+    }
+}
+
+pub(super) fn controls_button_clicked(
+    interactions: Query<&Interaction, (With<ControlsButton>, Changed<Interaction>)>,
+    mut game_state_next_state: ResMut<NextState<GameState>>,
+) {
+    for interaction in &interactions {
+        if matches!(interaction, Interaction::Clicked) {
+            game_state_next_state.set(GameState::Controls);
+        }
+    }
+}
+
 pub(super) fn quit_button_clicked(
     interactions: Query<&Interaction, (With<QuitButton>, Changed<Interaction>)>,
     mut exit: EventWriter<AppExit>,
@@ -126,18 +173,21 @@ fn spawn_button(
             ..default()
         })
         .with_children(|commands| {
-            commands.spawn(TextBundle {
-                style: TEXT_BUNDLE_STYLE,
-                text: Text::from_section(
-                    text,
-                    TextStyle {
-                        font: asset_server.load("FiraSans-Bold.ttf"),
-                        font_size: 64.0,
-                        color: Color::BEIGE,
-                    },
-                ),
-                ..default()
-            });
+            commands.spawn((
+                TextBundle {
+                    style: TEXT_BUNDLE_STYLE,
+                    text: Text::from_section(
+                        text,
+                        TextStyle {
+                            font: asset_server.load("FiraSans-Bold.ttf"),
+                            font_size: 64.0,
+                            color: Color::BEIGE,
+                        },
+                    ),
+                    ..default()
+                },
+                ScaledFont { base_size: 64.0 },
+            ));
         })
         .id()
 }
@@ -0,0 +1,35 @@
+mod components;
+mod events;
+mod resources;
+mod systems;
+
+use bevy::prelude::*;
+pub use components::*;
+pub use events::*;
+pub use resources::*;
+use systems::*;
+
+use crate::GameState;
+
+pub struct ParticlePlugin;
+
+impl Plugin for ParticlePlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Particle>()
+            .init_resource::<ParticlePool>()
+            .add_event::<ParticleEvent>()
+            .add_startup_system(spawn_particle_pool)
+            .add_systems(
+                (
+                    impact_particles,
+                    death_particles,
+                    muzzle_particles,
+                    emit_particles,
+                    update_particles,
+                )
+                    .chain()
+                    .in_set(OnUpdate(GameState::Gameplay)),
+            )
+            .add_system(clear_particles.in_schedule(OnExit(GameState::Gameplay)));
+    }
+}
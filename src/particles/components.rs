@@ -0,0 +1,26 @@
+use bevy::prelude::*;
+
+/// A pooled billboard quad, alive while `age < lifetime`.
+#[derive(Component, Default, Reflect)]
+#[reflect(Component)]
+pub struct Particle {
+    pub velocity: Vec3,
+    pub gravity: f32,
+    /// Fraction of velocity lost per second
+    pub drag: f32,
+    pub age: f32,
+    pub lifetime: f32,
+    pub start_color: Color,
+    pub end_color: Color,
+    pub start_size: f32,
+    pub end_size: f32,
+}
+
+impl Particle {
+    pub fn alive(&self) -> bool {
+        self.age < self.lifetime
+    }
+}
+
+#[derive(Component)]
+pub struct ParticleRoot;
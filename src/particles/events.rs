@@ -0,0 +1,18 @@
+use bevy::prelude::*;
+
+pub struct ParticleEvent {
+    pub position: Vec3,
+    /// Main direction of travel, zero for an even burst
+    pub direction: Vec3,
+    pub effect: ParticleEffect,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum ParticleEffect {
+    /// Juice thrown up from a bullet impact
+    Splat(Color),
+    /// Debris from a target dying
+    DeathBurst,
+    /// Smoke from a tower firing
+    MuzzlePuff,
+}
@@ -0,0 +1,69 @@
+use bevy::prelude::*;
+
+use super::events::ParticleEffect;
+
+pub const PARTICLE_POOL_SIZE: usize = 512;
+
+/// Particle entities that are hidden and ready to be reused.
+#[derive(Resource, Default)]
+pub struct ParticlePool {
+    pub free: Vec<Entity>,
+}
+
+/// How a `ParticleEffect` emits its particles.
+pub struct ParticlePreset {
+    pub count: u32,
+    pub speed: (f32, f32),
+    /// 0 fires straight along the event direction, 1 scatters in every direction
+    pub spread: f32,
+    pub gravity: f32,
+    pub drag: f32,
+    pub lifetime: (f32, f32),
+    pub start_color: Color,
+    pub end_color: Color,
+    pub start_size: f32,
+    pub end_size: f32,
+}
+
+impl ParticleEffect {
+    pub fn preset(&self) -> ParticlePreset {
+        match self {
+            ParticleEffect::Splat(color) => ParticlePreset {
+                count: 10,
+                speed: (1.5, 3.0),
+                spread: 1.0,
+                gravity: 9.0,
+                drag: 0.5,
+                lifetime: (0.3, 0.5),
+                start_color: *color,
+                end_color: color.with_a(0.0),
+                start_size: 0.12,
+                end_size: 0.04,
+            },
+            ParticleEffect::DeathBurst => ParticlePreset {
+                count: 24,
+                speed: (2.0, 4.0),
+                spread: 1.0,
+                gravity: 6.0,
+                drag: 1.0,
+                lifetime: (0.4, 0.8),
+                start_color: Color::rgb(0.95, 0.85, 0.6),
+                end_color: Color::rgba(0.4, 0.25, 0.1, 0.0),
+                start_size: 0.2,
+                end_size: 0.05,
+            },
+            ParticleEffect::MuzzlePuff => ParticlePreset {
+                count: 6,
+                speed: (0.6, 1.2),
+                spread: 0.35,
+                gravity: -0.5,
+                drag: 3.0,
+                lifetime: (0.25, 0.4),
+                start_color: Color::rgba(1.0, 1.0, 1.0, 0.6),
+                end_color: Color::rgba(0.8, 0.8, 0.8, 0.0),
+                start_size: 0.1,
+                end_size: 0.3,
+            },
+        }
+    }
+}
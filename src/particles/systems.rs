@@ -0,0 +1,205 @@
+use bevy::{pbr::NotShadowCaster, prelude::*};
+use rand::Rng;
+
+use crate::*;
+
+pub(super) fn spawn_particle_pool(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut pool: ResMut<ParticlePool>,
+) {
+    let quad = meshes.add(shape::Quad::new(Vec2::ONE).into());
+
+    commands
+        .spawn((
+            SpatialBundle::default(),
+            ParticleRoot,
+            Name::new("Particles"),
+        ))
+        .with_children(|commands| {
+            for _ in 0..PARTICLE_POOL_SIZE {
+                // Each particle gets its own material so it can fade independently
+                let material = materials.add(StandardMaterial {
+                    base_color: Color::WHITE,
+                    unlit: true,
+                    alpha_mode: AlphaMode::Blend,
+                    ..default()
+                });
+                let entity = commands
+                    .spawn((
+                        PbrBundle {
+                            mesh: quad.clone(),
+                            material,
+                            visibility: Visibility::Hidden,
+                            ..default()
+                        },
+                        Particle::default(),
+                        NotShadowCaster,
+                        Name::new("Particle"),
+                    ))
+                    .id();
+                pool.free.push(entity);
+            }
+        });
+}
+
+pub(super) fn impact_particles(
+    mut hit_events: EventReader<BulletHitEvent>,
+    towers: Query<&TowerType>,
+    mut particle_events: EventWriter<ParticleEvent>,
+) {
+    for event in hit_events.iter() {
+        let color = towers
+            .get(event.source)
+            .map(|tower_type| tower_type.splat_color())
+            .unwrap_or_else(|_| event.damage_type.color());
+
+        particle_events.send(ParticleEvent {
+            position: event.position,
+            direction: Vec3::Y,
+            effect: ParticleEffect::Splat(color),
+        });
+    }
+}
+
+pub(super) fn death_particles(
+    mut death_events: EventReader<TargetDeathEvent>,
+    mut particle_events: EventWriter<ParticleEvent>,
+) {
+    for event in death_events.iter() {
+        particle_events.send(ParticleEvent {
+            position: event.position,
+            direction: Vec3::ZERO,
+            effect: ParticleEffect::DeathBurst,
+        });
+    }
+}
+
+pub(super) fn muzzle_particles(
+    mut fired_events: EventReader<TowerFiredEvent>,
+    mut particle_events: EventWriter<ParticleEvent>,
+) {
+    for event in fired_events.iter() {
+        particle_events.send(ParticleEvent {
+            position: event.position,
+            direction: event.direction,
+            effect: ParticleEffect::MuzzlePuff,
+        });
+    }
+}
+
+pub(super) fn emit_particles(
+    mut particle_events: EventReader<ParticleEvent>,
+    mut particles: Query<(&mut Particle, &mut Transform, &mut Visibility)>,
+    mut pool: ResMut<ParticlePool>,
+) {
+    let mut rng = rand::thread_rng();
+
+    for event in particle_events.iter() {
+        let preset = event.effect.preset();
+        let direction = event.direction.normalize_or_zero();
+
+        'emit: for _ in 0..preset.count {
+            // Out of particles, effects just get thinner until some expire
+            let Some(entity) = pool.free.pop() else {
+                break 'emit;
+            };
+            // The pool is spawned once at startup and its entities are never despawned
+            let (mut particle, mut transform, mut visibility) = particles
+                .get_mut(entity)
+                .expect("pooled particle entity was despawned");
+
+            let scatter = Vec3::new(
+                rng.gen_range(-1.0..1.0),
+                rng.gen_range(-1.0..1.0),
+                rng.gen_range(-1.0..1.0),
+            )
+            .normalize_or_zero();
+            let heading = (direction + scatter * preset.spread).normalize_or_zero();
+
+            *particle = Particle {
+                velocity: heading * rng.gen_range(preset.speed.0..=preset.speed.1),
+                gravity: preset.gravity,
+                drag: preset.drag,
+                age: 0.0,
+                lifetime: rng.gen_range(preset.lifetime.0..=preset.lifetime.1),
+                start_color: preset.start_color,
+                end_color: preset.end_color,
+                start_size: preset.start_size,
+                end_size: preset.end_size,
+            };
+            transform.translation = event.position;
+            transform.scale = Vec3::splat(preset.start_size);
+            *visibility = Visibility::Inherited;
+        }
+    }
+}
+
+fn lerp_color(from: Color, to: Color, t: f32) -> Color {
+    let from = Vec4::from(from.as_rgba_f32());
+    let to = Vec4::from(to.as_rgba_f32());
+    let [r, g, b, a] = from.lerp(to, t).to_array();
+    Color::rgba(r, g, b, a)
+}
+
+pub(super) fn update_particles(
+    mut particles: Query<(
+        Entity,
+        &mut Particle,
+        &mut Transform,
+        &mut Visibility,
+        &Handle<StandardMaterial>,
+    )>,
+    camera: Query<&Transform, (With<Camera3d>, Without<Particle>)>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut pool: ResMut<ParticlePool>,
+    time: Res<Time>,
+) {
+    let facing = camera
+        .get_single()
+        .map(|camera| camera.rotation)
+        .unwrap_or_default();
+    let delta = time.delta_seconds();
+
+    for (entity, mut particle, mut transform, mut visibility, material) in &mut particles {
+        if !particle.alive() {
+            continue;
+        }
+
+        particle.age += delta;
+        if !particle.alive() {
+            *visibility = Visibility::Hidden;
+            pool.free.push(entity);
+            continue;
+        }
+
+        let gravity = particle.gravity;
+        let drag = (1.0 - particle.drag * delta).max(0.0);
+        particle.velocity.y -= gravity * delta;
+        particle.velocity *= drag;
+
+        let life = particle.age / particle.lifetime;
+        transform.translation += particle.velocity * delta;
+        transform.rotation = facing;
+        let size = particle.start_size + (particle.end_size - particle.start_size) * life;
+        transform.scale = Vec3::splat(size);
+
+        if let Some(material) = materials.get_mut(material) {
+            material.base_color = lerp_color(particle.start_color, particle.end_color, life);
+        }
+    }
+}
+
+pub(super) fn clear_particles(
+    mut particles: Query<(Entity, &mut Particle, &mut Visibility)>,
+    mut pool: ResMut<ParticlePool>,
+) {
+    for (entity, mut particle, mut visibility) in &mut particles {
+        if particle.alive() {
+            particle.lifetime = 0.0;
+            *visibility = Visibility::Hidden;
+            pool.free.push(entity);
+        }
+    }
+}
@@ -15,9 +15,7 @@ impl Plugin for PlayerPlugin {
             .add_systems(
                 (spawn_player, spawn_gameplay_ui).in_schedule(OnEnter(GameState::Gameplay)),
             )
-            .add_systems(
-                (give_money_on_kill, update_player_ui).in_set(OnUpdate(GameState::Gameplay)),
-            )
+            .add_system(update_player_ui.in_set(OnUpdate(GameState::Gameplay)))
             .add_system(despawn_player.in_schedule(OnExit(GameState::Gameplay)));
     }
 }
@@ -1,6 +1,6 @@
 use bevy::prelude::*;
 
-use crate::TargetDeathEvent;
+use crate::{CallWaveButton, CurrentLevel, EconomyRules, ScaledFont, WaveUI};
 
 use super::components::{GamePlayUIRoot, HealthUI, MoneyUI, Player};
 
@@ -68,7 +68,9 @@ pub(super) fn spawn_gameplay_ui(mut commands: Commands, asset_server: Res<AssetS
                             ..default()
                         },
                         MoneyUI,
+                        ScaledFont { base_size: 36.0 },
                     ));
+                    spawn_wave_controls(commands, &asset_server);
                     commands.spawn((
                         TextBundle {
                             style: Style {
@@ -86,15 +88,20 @@ pub(super) fn spawn_gameplay_ui(mut commands: Commands, asset_server: Res<AssetS
                             ..default()
                         },
                         HealthUI,
+                        ScaledFont { base_size: 36.0 },
                     ));
                 });
         });
 }
 
-pub(super) fn spawn_player(mut commands: Commands) {
+pub(super) fn spawn_player(
+    mut commands: Commands,
+    rules: Res<EconomyRules>,
+    level: Res<CurrentLevel>,
+) {
     commands.spawn((
         Player {
-            money: 100,
+            money: rules.starting_money(level.0),
             health: 10,
         },
         Name::new("Player"),
@@ -107,12 +114,64 @@ pub(super) fn despawn_player(mut commands: Commands, players: Query<Entity, With
     }
 }
 
-pub(super) fn give_money_on_kill(
-    mut player: Query<&mut Player>,
-    mut death_events: EventReader<TargetDeathEvent>,
-) {
-    let mut player = player.single_mut();
-    for _event in death_events.iter() {
-        player.money += 10;
-    }
+fn spawn_wave_controls(commands: &mut ChildBuilder, asset_server: &AssetServer) {
+    let font = asset_server.load("FiraSans-Bold.ttf");
+
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                margin: UiRect::all(Val::Percent(1.2)),
+                align_items: AlignItems::Center,
+                flex_direction: FlexDirection::Row,
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|commands| {
+            commands.spawn((
+                TextBundle::from_section(
+                    "Wave X/X",
+                    TextStyle {
+                        font: font.clone(),
+                        font_size: 28.0,
+                        color: Color::BLACK,
+                    },
+                ),
+                WaveUI,
+                ScaledFont { base_size: 28.0 },
+            ));
+            commands
+                .spawn((
+                    ButtonBundle {
+                        style: Style {
+                            padding: UiRect::new(
+                                Val::Px(12.0),
+                                Val::Px(12.0),
+                                Val::Px(4.0),
+                                Val::Px(4.0),
+                            ),
+                            margin: UiRect::left(Val::Px(12.0)),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            ..default()
+                        },
+                        background_color: Color::DARK_GREEN.into(),
+                        ..default()
+                    },
+                    CallWaveButton,
+                ))
+                .with_children(|commands| {
+                    commands.spawn((
+                        TextBundle::from_section(
+                            "Call Wave",
+                            TextStyle {
+                                font,
+                                font_size: 24.0,
+                                color: Color::BEIGE,
+                            },
+                        ),
+                        ScaledFont { base_size: 24.0 },
+                    ));
+                });
+        });
 }
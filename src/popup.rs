@@ -0,0 +1,31 @@
+mod components;
+mod events;
+mod resources;
+mod systems;
+
+use bevy::prelude::*;
+pub use components::*;
+pub use events::*;
+pub use resources::*;
+use systems::*;
+
+use crate::GameState;
+
+pub struct PopupPlugin;
+
+impl Plugin for PopupPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Popup>()
+            .init_resource::<PopupSettings>()
+            .add_event::<PopupEvent>()
+            .add_systems(
+                (
+                    damage_number_popups,
+                    spawn_popups.after(damage_number_popups),
+                    update_popups,
+                )
+                    .in_set(OnUpdate(GameState::Gameplay)),
+            )
+            .add_system(despawn_popups.in_schedule(OnExit(GameState::Gameplay)));
+    }
+}
@@ -0,0 +1,10 @@
+use bevy::prelude::*;
+
+/// Text that floats up from a world position and fades out.
+#[derive(Component, Default, Reflect)]
+#[reflect(Component)]
+pub struct Popup {
+    pub world_position: Vec3,
+    pub rise_speed: f32,
+    pub lifetime: Timer,
+}
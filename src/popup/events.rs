@@ -0,0 +1,19 @@
+use bevy::prelude::*;
+
+pub struct PopupEvent {
+    pub position: Vec3,
+    pub text: String,
+    pub color: Color,
+    pub scale: f32,
+}
+
+impl PopupEvent {
+    pub fn new(position: Vec3, text: impl Into<String>, color: Color) -> Self {
+        Self {
+            position,
+            text: text.into(),
+            color,
+            scale: 1.0,
+        }
+    }
+}
@@ -0,0 +1,12 @@
+use bevy::prelude::*;
+
+#[derive(Resource)]
+pub struct PopupSettings {
+    pub enabled: bool,
+}
+
+impl Default for PopupSettings {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
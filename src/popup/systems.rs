@@ -0,0 +1,148 @@
+use bevy::prelude::*;
+
+use crate::*;
+
+const POPUP_FONT_SIZE: f32 = 24.0;
+const CRIT_SCALE: f32 = 1.5;
+
+pub(super) fn damage_number_popups(
+    mut hit_events: EventReader<BulletHitEvent>,
+    mut popup_events: EventWriter<PopupEvent>,
+) {
+    for event in hit_events.iter() {
+        popup_events.send(PopupEvent {
+            scale: if event.crit { CRIT_SCALE } else { 1.0 },
+            ..PopupEvent::new(
+                event.position,
+                event.damage.to_string(),
+                event.damage_type.color(),
+            )
+        });
+    }
+}
+
+pub(super) fn spawn_popups(
+    mut commands: Commands,
+    mut popup_events: EventReader<PopupEvent>,
+    settings: Res<PopupSettings>,
+    asset_server: Res<AssetServer>,
+) {
+    if !settings.enabled {
+        popup_events.clear();
+        return;
+    }
+
+    for event in popup_events.iter() {
+        commands.spawn((
+            TextBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    ..default()
+                },
+                text: Text::from_section(
+                    event.text.clone(),
+                    TextStyle {
+                        font: asset_server.load("FiraSans-Bold.ttf"),
+                        font_size: POPUP_FONT_SIZE * event.scale,
+                        color: event.color,
+                    },
+                ),
+                // Hidden until the first projection places it on screen
+                visibility: Visibility::Hidden,
+                ..default()
+            },
+            Popup {
+                world_position: event.position,
+                rise_speed: 0.8,
+                lifetime: Timer::from_seconds(0.8, TimerMode::Once),
+            },
+            Name::new("Popup"),
+        ));
+    }
+}
+
+pub(super) fn update_popups(
+    mut commands: Commands,
+    mut popups: Query<(Entity, &mut Popup, &mut Style, &mut Text, &mut Visibility)>,
+    camera: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    ui_scale: Res<UiScale>,
+    time: Res<Time>,
+) {
+    let Ok((camera, camera_transform)) = camera.get_single() else {
+        return;
+    };
+
+    for (entity, mut popup, mut style, mut text, mut visibility) in &mut popups {
+        popup.lifetime.tick(time.delta());
+        if popup.lifetime.finished() {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        }
+
+        popup.world_position.y += popup.rise_speed * time.delta_seconds();
+
+        match camera.world_to_viewport(camera_transform, popup.world_position) {
+            Some(screen_position) => {
+                // UI pixels get multiplied by the UI scale, viewport ones don't
+                let screen_position = screen_position / ui_scale.scale as f32;
+                *visibility = Visibility::Inherited;
+                style.position.left = Val::Px(screen_position.x);
+                style.position.bottom = Val::Px(screen_position.y);
+            }
+            None => *visibility = Visibility::Hidden,
+        }
+
+        let alpha = 1.0 - popup.lifetime.percent();
+        for section in &mut text.sections {
+            section.style.color.set_a(alpha);
+        }
+    }
+}
+
+pub(super) fn despawn_popups(mut commands: Commands, popups: Query<Entity, With<Popup>>) {
+    for popup_entity in popups.iter() {
+        commands.entity(popup_entity).despawn_recursive();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hit(damage: i32, crit: bool) -> BulletHitEvent {
+        BulletHitEvent {
+            source: Entity::PLACEHOLDER,
+            target: Entity::PLACEHOLDER,
+            position: Vec3::new(1.0, 2.0, 3.0),
+            damage,
+            damage_type: DamageType::Acid,
+            crit,
+            lethal: false,
+        }
+    }
+
+    #[test]
+    fn hits_become_damage_numbers_and_crits_are_bigger() {
+        let mut app = App::new();
+        app.add_event::<BulletHitEvent>()
+            .add_event::<PopupEvent>()
+            .add_system(damage_number_popups);
+
+        app.world.send_event(hit(3, false));
+        app.world.send_event(hit(7, true));
+        app.update();
+
+        let events = app.world.resource::<Events<PopupEvent>>();
+        let mut reader = events.get_reader();
+        let popups: Vec<_> = reader.iter(events).collect();
+        assert_eq!(popups.len(), 2);
+
+        assert_eq!(popups[0].text, "3");
+        assert_eq!(popups[0].scale, 1.0);
+        assert_eq!(popups[0].color, DamageType::Acid.color());
+        assert_eq!(popups[0].position, Vec3::new(1.0, 2.0, 3.0));
+
+        assert_eq!(popups[1].text, "7");
+        assert_eq!(popups[1].scale, CRIT_SCALE);
+    }
+}
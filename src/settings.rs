@@ -0,0 +1,41 @@
+mod components;
+mod menu;
+mod resources;
+mod systems;
+
+use bevy::prelude::*;
+pub use components::*;
+use menu::*;
+pub use resources::*;
+use systems::*;
+
+use crate::GameState;
+
+pub struct SettingsPlugin;
+
+impl Plugin for SettingsPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.world.contains_resource::<Settings>() {
+            app.insert_resource(Settings::load());
+        }
+
+        app.add_systems((
+            apply_video_settings,
+            apply_popup_settings,
+            apply_audio_settings,
+            apply_shadow_settings,
+        ))
+        .add_system(apply_game_speed.in_schedule(OnEnter(GameState::Gameplay)))
+        .add_system(reset_game_speed.in_schedule(OnExit(GameState::Gameplay)))
+        .add_system(spawn_settings_menu.in_schedule(OnEnter(GameState::Settings)))
+        .add_system(despawn_settings_menu.in_schedule(OnExit(GameState::Settings)))
+        .add_systems(
+            (
+                setting_button_clicked,
+                update_setting_labels.after(setting_button_clicked),
+                settings_back_button_clicked,
+            )
+                .in_set(OnUpdate(GameState::Settings)),
+        );
+    }
+}
@@ -0,0 +1,19 @@
+use bevy::prelude::*;
+
+use super::resources::SettingKind;
+
+#[derive(Component)]
+pub struct SettingsUIRoot;
+
+/// Steps a setting backwards (`-1`) or forwards (`1`) when clicked.
+#[derive(Component)]
+pub struct SettingButton {
+    pub kind: SettingKind,
+    pub step: i32,
+}
+
+#[derive(Component)]
+pub struct SettingValueText(pub SettingKind);
+
+#[derive(Component)]
+pub struct SettingsBackButton;
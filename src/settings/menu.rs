@@ -0,0 +1,191 @@
+use bevy::prelude::*;
+
+use crate::{GameState, ScaledFont};
+
+use super::{
+    components::{SettingButton, SettingValueText, SettingsBackButton, SettingsUIRoot},
+    resources::{SettingKind, Settings},
+};
+
+pub(super) const ROW_STYLE: Style = Style {
+    size: Size::new(Val::Percent(45.0), Val::Px(40.0)),
+    align_self: AlignSelf::Center,
+    align_items: AlignItems::Center,
+    margin: UiRect::all(Val::Px(3.0)),
+    ..Style::DEFAULT
+};
+
+pub(super) const ARROW_BUTTON_STYLE: Style = Style {
+    size: Size::new(Val::Px(40.0), Val::Percent(100.0)),
+    justify_content: JustifyContent::Center,
+    align_items: AlignItems::Center,
+    ..Style::DEFAULT
+};
+
+pub(super) fn spawn_settings_menu(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    settings: Res<Settings>,
+) {
+    let font = asset_server.load("FiraSans-Bold.ttf");
+    let text_style = |font_size: f32| TextStyle {
+        font: font.clone(),
+        font_size,
+        color: Color::BEIGE,
+    };
+
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    size: Size::new(Val::Percent(100.0), Val::Percent(100.0)),
+                    justify_content: JustifyContent::Center,
+                    flex_direction: FlexDirection::Column,
+                    ..default()
+                },
+                ..default()
+            },
+            SettingsUIRoot,
+        ))
+        .with_children(|commands| {
+            commands.spawn((
+                TextBundle::from_section("Settings", text_style(64.0)).with_style(Style {
+                    align_self: AlignSelf::Center,
+                    margin: UiRect::all(Val::Px(12.0)),
+                    ..default()
+                }),
+                ScaledFont { base_size: 64.0 },
+            ));
+
+            for kind in SettingKind::ALL {
+                commands
+                    .spawn(NodeBundle {
+                        style: ROW_STYLE,
+                        ..default()
+                    })
+                    .with_children(|commands| {
+                        commands.spawn((
+                            TextBundle::from_section(kind.name(), text_style(26.0)).with_style(
+                                Style {
+                                    flex_grow: 1.0,
+                                    ..default()
+                                },
+                            ),
+                            ScaledFont { base_size: 26.0 },
+                        ));
+                        spawn_arrow_button(commands, text_style(26.0), "<", kind, -1);
+                        commands.spawn((
+                            TextBundle::from_section(settings.label(kind), text_style(26.0))
+                                .with_style(Style {
+                                    size: Size::new(Val::Px(160.0), Val::Auto),
+                                    justify_content: JustifyContent::Center,
+                                    margin: UiRect::horizontal(Val::Px(8.0)),
+                                    ..default()
+                                })
+                                .with_text_alignment(TextAlignment::Center),
+                            SettingValueText(kind),
+                            ScaledFont { base_size: 26.0 },
+                        ));
+                        spawn_arrow_button(commands, text_style(26.0), ">", kind, 1);
+                    });
+            }
+
+            commands
+                .spawn((
+                    ButtonBundle {
+                        style: Style {
+                            size: Size::new(Val::Px(220.0), Val::Px(48.0)),
+                            align_self: AlignSelf::Center,
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            margin: UiRect::all(Val::Px(16.0)),
+                            ..default()
+                        },
+                        background_color: Color::RED.into(),
+                        ..default()
+                    },
+                    SettingsBackButton,
+                ))
+                .with_children(|commands| {
+                    commands.spawn((
+                        TextBundle::from_section("Back", text_style(28.0)),
+                        ScaledFont { base_size: 28.0 },
+                    ));
+                });
+        });
+}
+
+fn spawn_arrow_button(
+    commands: &mut ChildBuilder,
+    text_style: TextStyle,
+    text: &str,
+    kind: SettingKind,
+    step: i32,
+) {
+    commands
+        .spawn((
+            ButtonBundle {
+                style: ARROW_BUTTON_STYLE,
+                background_color: Color::DARK_GRAY.into(),
+                ..default()
+            },
+            SettingButton { kind, step },
+        ))
+        .with_children(|commands| {
+            let base_size = text_style.font_size;
+            commands.spawn((
+                TextBundle::from_section(text, text_style),
+                ScaledFont { base_size },
+            ));
+        });
+}
+
+pub(super) fn despawn_settings_menu(
+    mut commands: Commands,
+    menus: Query<Entity, With<SettingsUIRoot>>,
+) {
+    for menu_entity in menus.iter() {
+        commands.entity(menu_entity).despawn_recursive();
+    }
+}
+
+pub(super) fn setting_button_clicked(
+    interactions: Query<(&Interaction, &SettingButton), Changed<Interaction>>,
+    mut settings: ResMut<Settings>,
+) {
+    let mut changed = false;
+    for (interaction, button) in &interactions {
+        if matches!(interaction, Interaction::Clicked) {
+            settings.adjust(button.kind, button.step);
+            changed = true;
+        }
+    }
+
+    if changed {
+        settings.save();
+    }
+}
+
+pub(super) fn update_setting_labels(
+    mut labels: Query<(&mut Text, &SettingValueText)>,
+    settings: Res<Settings>,
+) {
+    if !settings.is_changed() {
+        return;
+    }
+
+    for (mut text, label) in &mut labels {
+        text.sections[0].value = settings.label(label.0);
+    }
+}
+
+pub(super) fn settings_back_button_clicked(
+    interactions: Query<&Interaction, (With<SettingsBackButton>, Changed<Interaction>)>,
+    mut game_state_next_state: ResMut<NextState<GameState>>,
+) {
+    for interaction in &interactions {
+        if matches!(interaction, Interaction::Clicked) {
+            game_state_next_state.set(GameState::MainMenu);
+        }
+    }
+}
@@ -0,0 +1,273 @@
+use bevy::{prelude::*, window::WindowMode};
+use serde::{Deserialize, Serialize};
+
+use crate::config::{load_config, save_config};
+
+const SETTINGS_FILE: &str = "settings.ron";
+
+pub const RESOLUTIONS: [[u32; 2]; 5] = [
+    [1024, 576],
+    [1280, 720],
+    [1600, 900],
+    [1920, 1080],
+    [2560, 1440],
+];
+const UI_SCALES: [f64; 4] = [0.75, 1.0, 1.25, 1.5];
+const GAME_SPEEDS: [f32; 4] = [0.5, 1.0, 1.5, 2.0];
+const VOLUME_STEP: f32 = 0.1;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WindowModeSetting {
+    #[default]
+    Windowed,
+    Borderless,
+    Fullscreen,
+}
+
+impl WindowModeSetting {
+    pub fn window_mode(&self) -> WindowMode {
+        match self {
+            WindowModeSetting::Windowed => WindowMode::Windowed,
+            WindowModeSetting::Borderless => WindowMode::BorderlessFullscreen,
+            WindowModeSetting::Fullscreen => WindowMode::Fullscreen,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SettingKind {
+    MasterVolume,
+    MusicVolume,
+    SfxVolume,
+    WindowMode,
+    Resolution,
+    UiScale,
+    Shadows,
+    DamageNumbers,
+    GameSpeed,
+}
+
+impl SettingKind {
+    pub const ALL: [SettingKind; 9] = [
+        SettingKind::MasterVolume,
+        SettingKind::MusicVolume,
+        SettingKind::SfxVolume,
+        SettingKind::WindowMode,
+        SettingKind::Resolution,
+        SettingKind::UiScale,
+        SettingKind::Shadows,
+        SettingKind::DamageNumbers,
+        SettingKind::GameSpeed,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            SettingKind::MasterVolume => "Master Volume",
+            SettingKind::MusicVolume => "Music Volume",
+            SettingKind::SfxVolume => "SFX Volume",
+            SettingKind::WindowMode => "Window Mode",
+            SettingKind::Resolution => "Resolution",
+            SettingKind::UiScale => "UI Scale",
+            SettingKind::Shadows => "Shadows",
+            SettingKind::DamageNumbers => "Damage Numbers",
+            SettingKind::GameSpeed => "Game Speed",
+        }
+    }
+}
+
+/// Player preferences, persisted to the user's config dir.
+#[derive(Resource, Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    pub master_volume: f32,
+    pub music_volume: f32,
+    pub sfx_volume: f32,
+    pub window_mode: WindowModeSetting,
+    pub resolution: [u32; 2],
+    pub ui_scale: f64,
+    pub shadows: bool,
+    pub damage_numbers: bool,
+    pub game_speed: f32,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            master_volume: 1.0,
+            music_volume: 0.7,
+            sfx_volume: 1.0,
+            window_mode: WindowModeSetting::Windowed,
+            resolution: [1280, 720],
+            ui_scale: 1.0,
+            shadows: true,
+            damage_numbers: true,
+            game_speed: 1.0,
+        }
+    }
+}
+
+/// Moves `step` places through `options` from the entry closest to `current`, wrapping around.
+fn cycle<T: Copy>(options: &[T], current: usize, step: i32) -> T {
+    let len = options.len() as i32;
+    options[(current as i32 + step).rem_euclid(len) as usize]
+}
+
+fn closest_index<T: Copy + Into<f64>>(options: &[T], value: T) -> usize {
+    let value = value.into();
+    (0..options.len())
+        .min_by(|a, b| {
+            let a = (options[*a].into() - value).abs();
+            let b = (options[*b].into() - value).abs();
+            a.total_cmp(&b)
+        })
+        .unwrap_or(0)
+}
+
+impl Settings {
+    pub fn load() -> Self {
+        load_config(SETTINGS_FILE).unwrap_or_default()
+    }
+
+    pub fn save(&self) {
+        save_config(SETTINGS_FILE, self);
+    }
+
+    pub fn adjust(&mut self, kind: SettingKind, step: i32) {
+        match kind {
+            SettingKind::MasterVolume => {
+                self.master_volume =
+                    (self.master_volume + VOLUME_STEP * step as f32).clamp(0.0, 1.0)
+            }
+            SettingKind::MusicVolume => {
+                self.music_volume = (self.music_volume + VOLUME_STEP * step as f32).clamp(0.0, 1.0)
+            }
+            SettingKind::SfxVolume => {
+                self.sfx_volume = (self.sfx_volume + VOLUME_STEP * step as f32).clamp(0.0, 1.0)
+            }
+            SettingKind::WindowMode => {
+                let modes = [
+                    WindowModeSetting::Windowed,
+                    WindowModeSetting::Borderless,
+                    WindowModeSetting::Fullscreen,
+                ];
+                let current = modes
+                    .iter()
+                    .position(|m| *m == self.window_mode)
+                    .unwrap_or(0);
+                self.window_mode = cycle(&modes, current, step);
+            }
+            SettingKind::Resolution => {
+                let current = RESOLUTIONS
+                    .iter()
+                    .position(|r| *r == self.resolution)
+                    .unwrap_or(1);
+                self.resolution = cycle(&RESOLUTIONS, current, step);
+            }
+            SettingKind::UiScale => {
+                self.ui_scale = cycle(&UI_SCALES, closest_index(&UI_SCALES, self.ui_scale), step)
+            }
+            SettingKind::Shadows => self.shadows = !self.shadows,
+            SettingKind::DamageNumbers => self.damage_numbers = !self.damage_numbers,
+            SettingKind::GameSpeed => {
+                self.game_speed = cycle(
+                    &GAME_SPEEDS,
+                    closest_index(&GAME_SPEEDS, self.game_speed),
+                    step,
+                )
+            }
+        }
+    }
+
+    pub fn label(&self, kind: SettingKind) -> String {
+        let on_off = |value: bool| if value { "On" } else { "Off" }.to_string();
+        match kind {
+            SettingKind::MasterVolume => format!("{:.0}%", self.master_volume * 100.0),
+            SettingKind::MusicVolume => format!("{:.0}%", self.music_volume * 100.0),
+            SettingKind::SfxVolume => format!("{:.0}%", self.sfx_volume * 100.0),
+            SettingKind::WindowMode => format!("{:?}", self.window_mode),
+            SettingKind::Resolution => format!("{}x{}", self.resolution[0], self.resolution[1]),
+            SettingKind::UiScale => format!("{:.0}%", self.ui_scale * 100.0),
+            SettingKind::Shadows => on_off(self.shadows),
+            SettingKind::DamageNumbers => on_off(self.damage_numbers),
+            SettingKind::GameSpeed => format!("{}x", self.game_speed),
+        }
+    }
+
+    /// Combined volume for sound effects.
+    pub fn sfx_gain(&self) -> f32 {
+        self.master_volume * self.sfx_volume
+    }
+
+    /// Combined volume for music.
+    pub fn music_gain(&self) -> f32 {
+        self.master_volume * self.music_volume
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cycle_wraps_both_ways() {
+        let options = [1, 2, 3];
+        assert_eq!(cycle(&options, 2, 1), 1);
+        assert_eq!(cycle(&options, 0, -1), 3);
+        assert_eq!(cycle(&options, 1, 1), 3);
+    }
+
+    #[test]
+    fn closest_index_snaps_values_off_the_list() {
+        assert_eq!(closest_index(&UI_SCALES, 1.0), 1);
+        assert_eq!(closest_index(&UI_SCALES, 1.3), 2);
+        assert_eq!(closest_index(&GAME_SPEEDS, 9.0), GAME_SPEEDS.len() - 1);
+        assert_eq!(closest_index::<f32>(&[], 1.0), 0);
+    }
+
+    #[test]
+    fn ui_scale_steps_from_a_hand_edited_value() {
+        let mut settings = Settings {
+            ui_scale: 1.2,
+            ..default()
+        };
+        settings.adjust(SettingKind::UiScale, 1);
+        assert_eq!(settings.ui_scale, 1.5);
+    }
+
+    #[test]
+    fn resolution_and_window_mode_wrap_around() {
+        let mut settings = Settings {
+            resolution: RESOLUTIONS[RESOLUTIONS.len() - 1],
+            window_mode: WindowModeSetting::Windowed,
+            ..default()
+        };
+        settings.adjust(SettingKind::Resolution, 1);
+        settings.adjust(SettingKind::WindowMode, -1);
+        assert_eq!(settings.resolution, RESOLUTIONS[0]);
+        assert_eq!(settings.window_mode, WindowModeSetting::Fullscreen);
+    }
+
+    #[test]
+    fn volume_is_clamped() {
+        let mut settings = Settings::default();
+        for _ in 0..20 {
+            settings.adjust(SettingKind::MasterVolume, 1);
+        }
+        assert_eq!(settings.master_volume, 1.0);
+
+        for _ in 0..20 {
+            settings.adjust(SettingKind::SfxVolume, -1);
+        }
+        assert_eq!(settings.sfx_volume, 0.0);
+        assert_eq!(settings.sfx_gain(), 0.0);
+    }
+
+    #[test]
+    fn toggles_flip() {
+        let mut settings = Settings::default();
+        settings.adjust(SettingKind::Shadows, 1);
+        settings.adjust(SettingKind::DamageNumbers, -1);
+        assert!(!settings.shadows);
+        assert!(!settings.damage_numbers);
+    }
+}
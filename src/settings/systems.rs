@@ -0,0 +1,67 @@
+use bevy::{prelude::*, window::PrimaryWindow};
+
+use crate::{MusicVolume, PopupSettings, SfxVolume};
+
+use super::resources::{Settings, WindowModeSetting};
+
+pub(super) fn apply_video_settings(
+    settings: Res<Settings>,
+    mut windows: Query<&mut Window, With<PrimaryWindow>>,
+    mut ui_scale: ResMut<UiScale>,
+    mut applied: Local<Option<(WindowModeSetting, [u32; 2])>>,
+) {
+    if !settings.is_changed() {
+        return;
+    }
+
+    // Leave a window the player resized alone unless the video options themselves changed
+    let video = (settings.window_mode, settings.resolution);
+    if *applied != Some(video) {
+        if let Ok(mut window) = windows.get_single_mut() {
+            window.mode = settings.window_mode.window_mode();
+            let [width, height] = settings.resolution;
+            window.resolution.set(width as f32, height as f32);
+            *applied = Some(video);
+        }
+    }
+    ui_scale.scale = settings.ui_scale;
+}
+
+pub(super) fn apply_popup_settings(settings: Res<Settings>, mut popups: ResMut<PopupSettings>) {
+    if settings.is_changed() {
+        popups.enabled = settings.damage_numbers;
+    }
+}
+
+pub(super) fn apply_audio_settings(
+    settings: Res<Settings>,
+    mut sfx_volume: ResMut<SfxVolume>,
+    mut music_volume: ResMut<MusicVolume>,
+) {
+    if settings.is_changed() {
+        sfx_volume.0 = settings.sfx_gain();
+        music_volume.0 = settings.music_gain();
+    }
+}
+
+pub(super) fn apply_shadow_settings(
+    settings: Res<Settings>,
+    mut point_lights: Query<&mut PointLight>,
+    new_lights: Query<(), Added<PointLight>>,
+) {
+    if !settings.is_changed() && new_lights.is_empty() {
+        return;
+    }
+
+    for mut light in &mut point_lights {
+        light.shadows_enabled = settings.shadows;
+    }
+}
+
+pub(super) fn apply_game_speed(settings: Res<Settings>, mut time: ResMut<Time>) {
+    time.set_relative_speed(settings.game_speed);
+}
+
+pub(super) fn reset_game_speed(mut time: ResMut<Time>) {
+    time.set_relative_speed(1.0);
+}
@@ -1,12 +1,15 @@
 mod components;
 mod events;
+mod health_bar;
 mod resources;
 mod systems;
 
 use bevy::prelude::*;
 pub use components::*;
 pub use events::*;
-use resources::*;
+use health_bar::*;
+pub use resources::*;
+pub use systems::spawn_target;
 use systems::*;
 
 use crate::GameState;
@@ -17,6 +20,9 @@ impl Plugin for TargetPlugin {
     fn build(&self, app: &mut App) {
         app.register_type::<Target>()
             .register_type::<Health>()
+            .register_type::<Shield>()
+            .register_type::<Aura>()
+            .register_type::<EnemyType>()
             .insert_resource(TargetPath {
                 waypoints: vec![
                     Vec2::new(6.0, 2.0),
@@ -25,8 +31,17 @@ impl Plugin for TargetPlugin {
                 ],
             })
             .add_event::<TargetDeathEvent>()
+            .add_startup_system(setup_health_bar_assets)
             .add_systems(
-                (move_targets, hurt_player.after(move_targets), target_death)
+                (
+                    move_targets,
+                    hurt_player.after(move_targets),
+                    target_death.after(hurt_player),
+                    split_on_death.after(target_death),
+                    apply_auras.after(target_death),
+                    spawn_health_bars,
+                    update_health_bars.after(spawn_health_bars),
+                )
                     .in_set(OnUpdate(GameState::Gameplay)),
             )
             .add_system(despawn_targets.in_schedule(OnExit(GameState::Gameplay)));
@@ -0,0 +1,237 @@
+use bevy::prelude::*;
+
+use crate::GameAssets;
+
+#[derive(Component, Default, Reflect)]
+#[reflect(Component)]
+pub struct Target {
+    pub speed: f32,
+    pub path_index: usize,
+}
+
+#[derive(Component, Default, Reflect)]
+#[reflect(Component)]
+pub struct Health {
+    pub value: i32,
+    pub max: i32,
+}
+
+impl Health {
+    pub fn new(max: i32) -> Self {
+        Self { value: max, max }
+    }
+
+    pub fn heal(&mut self, amount: i32) {
+        self.value = (self.value + amount).min(self.max);
+    }
+
+    /// Applies `damage` after `shield` soaks up as much of it as it can.
+    /// Returns whether this hit took the target from alive to dead.
+    pub fn take_damage(&mut self, shield: Option<&mut Shield>, damage: i32) -> bool {
+        let mut remaining = damage;
+        if let Some(shield) = shield {
+            let absorbed = remaining.min(shield.value);
+            shield.value -= absorbed;
+            remaining -= absorbed;
+        }
+
+        let was_alive = self.value > 0;
+        self.value -= remaining;
+        was_alive && self.value <= 0
+    }
+}
+
+/// Billboarded bar spawned as a child of every `Target`.
+#[derive(Component)]
+pub struct HealthBar {
+    pub fill: Entity,
+}
+
+#[derive(Component)]
+pub struct HealthBarFill;
+
+/// Absorbs incoming damage before it reaches `Health`.
+#[derive(Component, Default, Reflect)]
+#[reflect(Component)]
+pub struct Shield {
+    pub value: i32,
+}
+
+/// Periodically buffs every target within `radius`, including the owner.
+#[derive(Component, Default, Reflect)]
+#[reflect(Component)]
+pub struct Aura {
+    pub kind: AuraKind,
+    pub radius: f32,
+    pub timer: Timer,
+}
+
+#[derive(Clone, Copy, Debug, Reflect, FromReflect)]
+pub enum AuraKind {
+    Heal { amount: i32 },
+    Shield { amount: i32, max: i32 },
+}
+
+impl Default for AuraKind {
+    fn default() -> Self {
+        AuraKind::Heal { amount: 0 }
+    }
+}
+
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Component)]
+pub enum EnemyType {
+    #[default]
+    Basic,
+    Splitter,
+    Splitling,
+    Healer,
+    Shielder,
+    Boss,
+}
+
+/// What happens when a target of a given `EnemyType` dies.
+#[derive(Clone, Copy, Debug)]
+pub enum OnDeath {
+    Split { into: EnemyType, count: u32 },
+}
+
+impl EnemyType {
+    pub(super) fn get_enemy(&self, assets: &GameAssets) -> (Handle<Scene>, Target, Health, f32) {
+        match self {
+            EnemyType::Basic => (
+                assets.target_scene.clone(),
+                Target {
+                    speed: 0.45,
+                    ..Default::default()
+                },
+                Health::new(3),
+                1.0,
+            ),
+            EnemyType::Splitter => (
+                assets.target_scene.clone(),
+                Target {
+                    speed: 0.35,
+                    ..Default::default()
+                },
+                Health::new(5),
+                1.3,
+            ),
+            EnemyType::Splitling => (
+                assets.target_scene.clone(),
+                Target {
+                    speed: 0.6,
+                    ..Default::default()
+                },
+                Health::new(1),
+                0.6,
+            ),
+            EnemyType::Healer => (
+                assets.target_scene.clone(),
+                Target {
+                    speed: 0.4,
+                    ..Default::default()
+                },
+                Health::new(4),
+                1.1,
+            ),
+            EnemyType::Shielder => (
+                assets.target_scene.clone(),
+                Target {
+                    speed: 0.4,
+                    ..Default::default()
+                },
+                Health::new(4),
+                1.1,
+            ),
+            EnemyType::Boss => (
+                assets.target_scene.clone(),
+                Target {
+                    speed: 0.25,
+                    ..Default::default()
+                },
+                Health::new(40),
+                2.0,
+            ),
+        }
+    }
+
+    pub(super) fn aura(&self) -> Option<Aura> {
+        match self {
+            EnemyType::Healer => Some(Aura {
+                kind: AuraKind::Heal { amount: 1 },
+                radius: 2.5,
+                timer: Timer::from_seconds(2.0, TimerMode::Repeating),
+            }),
+            EnemyType::Shielder => Some(Aura {
+                kind: AuraKind::Shield { amount: 1, max: 3 },
+                radius: 2.5,
+                timer: Timer::from_seconds(3.0, TimerMode::Repeating),
+            }),
+            EnemyType::Basic | EnemyType::Splitter | EnemyType::Splitling | EnemyType::Boss => None,
+        }
+    }
+
+    pub(super) fn on_death(&self) -> Option<OnDeath> {
+        match self {
+            EnemyType::Splitter => Some(OnDeath::Split {
+                into: EnemyType::Splitling,
+                count: 3,
+            }),
+            EnemyType::Boss => Some(OnDeath::Split {
+                into: EnemyType::Splitter,
+                count: 2,
+            }),
+            EnemyType::Basic | EnemyType::Splitling | EnemyType::Healer | EnemyType::Shielder => {
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shield_absorbs_damage_before_health() {
+        let mut health = Health::new(5);
+        let mut shield = Shield { value: 3 };
+
+        assert!(!health.take_damage(Some(&mut shield), 2));
+        assert_eq!(shield.value, 1);
+        assert_eq!(health.value, 5);
+
+        assert!(!health.take_damage(Some(&mut shield), 3));
+        assert_eq!(shield.value, 0);
+        assert_eq!(health.value, 3);
+    }
+
+    #[test]
+    fn only_the_killing_hit_is_lethal() {
+        let mut health = Health::new(2);
+
+        assert!(health.take_damage(None, 2));
+        assert_eq!(health.value, 0);
+        // Already dead, so an overlapping hit doesn't count as another kill
+        assert!(!health.take_damage(None, 1));
+    }
+
+    #[test]
+    fn shield_can_soak_a_lethal_hit() {
+        let mut health = Health::new(1);
+        let mut shield = Shield { value: 4 };
+
+        assert!(!health.take_damage(Some(&mut shield), 4));
+        assert_eq!(health.value, 1);
+        assert!(health.take_damage(Some(&mut shield), 1));
+    }
+
+    #[test]
+    fn heal_stops_at_max() {
+        let mut health = Health::new(5);
+        health.value = 2;
+        health.heal(10);
+        assert_eq!(health.value, 5);
+    }
+}
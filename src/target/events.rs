@@ -0,0 +1,9 @@
+use bevy::prelude::*;
+
+use super::components::EnemyType;
+
+pub struct TargetDeathEvent {
+    pub position: Vec3,
+    pub path_index: usize,
+    pub enemy_type: EnemyType,
+}
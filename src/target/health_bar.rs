@@ -0,0 +1,103 @@
+use bevy::{pbr::NotShadowCaster, prelude::*};
+
+use crate::*;
+
+use super::resources::HealthBarAssets;
+
+const HEALTH_BAR_SIZE: Vec2 = Vec2::new(0.8, 0.1);
+const HEALTH_BAR_HEIGHT: f32 = 0.9;
+
+pub(super) fn setup_health_bar_assets(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    commands.insert_resource(HealthBarAssets {
+        mesh: meshes.add(shape::Quad::new(HEALTH_BAR_SIZE).into()),
+        background: materials.add(StandardMaterial {
+            base_color: Color::rgb(0.15, 0.15, 0.15),
+            unlit: true,
+            ..default()
+        }),
+        fill: materials.add(StandardMaterial {
+            base_color: Color::rgb(0.8, 0.1, 0.1),
+            unlit: true,
+            ..default()
+        }),
+    });
+}
+
+pub(super) fn spawn_health_bars(
+    mut commands: Commands,
+    targets: Query<Entity, Added<Target>>,
+    health_bar_assets: Res<HealthBarAssets>,
+) {
+    for target_entity in &targets {
+        let fill = commands
+            .spawn((
+                PbrBundle {
+                    mesh: health_bar_assets.mesh.clone(),
+                    material: health_bar_assets.fill.clone(),
+                    // Nudged towards the camera so it draws over the background
+                    transform: Transform::from_xyz(0.0, 0.0, 0.001),
+                    ..default()
+                },
+                NotShadowCaster,
+                HealthBarFill,
+                Name::new("Health_Bar_Fill"),
+            ))
+            .id();
+
+        let bar = commands
+            .spawn((
+                PbrBundle {
+                    mesh: health_bar_assets.mesh.clone(),
+                    material: health_bar_assets.background.clone(),
+                    transform: Transform::from_xyz(0.0, HEALTH_BAR_HEIGHT, 0.0),
+                    visibility: Visibility::Hidden,
+                    ..default()
+                },
+                NotShadowCaster,
+                HealthBar { fill },
+                Name::new("Health_Bar"),
+            ))
+            .add_child(fill)
+            .id();
+
+        commands.entity(target_entity).add_child(bar);
+    }
+}
+
+pub(super) fn update_health_bars(
+    mut bars: Query<(&HealthBar, &Parent, &mut Transform, &mut Visibility)>,
+    mut fills: Query<&mut Transform, (With<HealthBarFill>, Without<HealthBar>)>,
+    targets: Query<(&Health, &GlobalTransform), With<Target>>,
+    camera: Query<&GlobalTransform, With<Camera3d>>,
+) {
+    let Ok(camera) = camera.get_single() else {
+        return;
+    };
+    let (_, camera_rotation, _) = camera.to_scale_rotation_translation();
+
+    for (bar, parent, mut transform, mut visibility) in &mut bars {
+        let Ok((health, target_transform)) = targets.get(parent.get()) else {
+            continue;
+        };
+
+        if health.value >= health.max {
+            *visibility = Visibility::Hidden;
+            continue;
+        }
+        *visibility = Visibility::Inherited;
+
+        // Undo the target's rotation so the bar always faces the camera
+        let (_, target_rotation, _) = target_transform.to_scale_rotation_translation();
+        transform.rotation = target_rotation.inverse() * camera_rotation;
+
+        if let Ok(mut fill_transform) = fills.get_mut(bar.fill) {
+            let fraction = (health.value as f32 / health.max as f32).clamp(0.0, 1.0);
+            fill_transform.scale.x = fraction;
+            fill_transform.translation.x = -(1.0 - fraction) * HEALTH_BAR_SIZE.x / 2.0;
+        }
+    }
+}
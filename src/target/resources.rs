@@ -0,0 +1,64 @@
+use bevy::{math::Vec3Swizzles, prelude::*};
+
+#[derive(Resource)]
+pub struct TargetPath {
+    pub waypoints: Vec<Vec2>,
+}
+
+impl TargetPath {
+    /// Distance left to walk for a target at `position` heading to waypoint `path_index`.
+    pub fn remaining_distance(&self, path_index: usize, position: Vec3) -> f32 {
+        let Some(next) = self.waypoints.get(path_index) else {
+            return 0.0;
+        };
+
+        let to_next = Vec2::distance(position.xz(), *next);
+        let rest: f32 = self.waypoints[path_index..]
+            .windows(2)
+            .map(|segment| Vec2::distance(segment[0], segment[1]))
+            .sum();
+        to_next + rest
+    }
+}
+
+#[derive(Resource)]
+pub struct HealthBarAssets {
+    pub mesh: Handle<Mesh>,
+    pub background: Handle<StandardMaterial>,
+    pub fill: Handle<StandardMaterial>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn path() -> TargetPath {
+        TargetPath {
+            waypoints: vec![
+                Vec2::new(0.0, 0.0),
+                Vec2::new(0.0, 4.0),
+                Vec2::new(3.0, 4.0),
+            ],
+        }
+    }
+
+    #[test]
+    fn counts_the_way_to_the_next_waypoint_and_the_rest_of_the_path() {
+        // 2 to reach (0, 4), then 3 more to the end
+        let remaining = path().remaining_distance(1, Vec3::new(0.0, 1.0, 2.0));
+        assert!((remaining - 5.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn ignores_height() {
+        let low = path().remaining_distance(2, Vec3::new(0.0, 0.0, 4.0));
+        let high = path().remaining_distance(2, Vec3::new(0.0, 10.0, 4.0));
+        assert!((low - 3.0).abs() < 1e-5);
+        assert_eq!(low, high);
+    }
+
+    #[test]
+    fn nothing_left_past_the_last_waypoint() {
+        assert_eq!(path().remaining_distance(3, Vec3::ZERO), 0.0);
+    }
+}
@@ -0,0 +1,176 @@
+use crate::*;
+use bevy::{math::Vec3Swizzles, prelude::*};
+
+use super::{events::TargetDeathEvent, resources::TargetPath};
+
+pub fn spawn_target(
+    commands: &mut Commands,
+    assets: &GameAssets,
+    enemy_type: EnemyType,
+    position: Vec3,
+    path_index: usize,
+) -> Entity {
+    let (scene, target, health, scale) = enemy_type.get_enemy(assets);
+    let mut entity = commands.spawn((
+        SceneBundle {
+            scene,
+            transform: Transform::from_translation(position).with_scale(Vec3::splat(scale)),
+            ..default()
+        },
+        Target {
+            path_index,
+            ..target
+        },
+        health,
+        enemy_type,
+        Name::new(format!("{:?}_Target", enemy_type)),
+    ));
+
+    if let Some(aura) = enemy_type.aura() {
+        entity.insert(aura);
+    }
+
+    entity.id()
+}
+
+pub(super) fn despawn_targets(mut commands: Commands, targets: Query<Entity, With<Target>>) {
+    for target_entity in targets.iter() {
+        commands.entity(target_entity).despawn_recursive();
+    }
+}
+
+pub(super) fn move_targets(
+    mut targets: Query<(&mut Target, &mut Transform)>,
+    path: Res<TargetPath>,
+    time: Res<Time>,
+) {
+    for (mut target, mut transform) in &mut targets {
+        // Past the last waypoint, `hurt_player` takes it from here
+        let Some(waypoint) = path.waypoints.get(target.path_index).copied() else {
+            continue;
+        };
+        let delta = target.speed * time.delta_seconds();
+        let delta_target = waypoint - transform.translation.xz();
+
+        // This step will get us closer to the goal
+        if delta_target.length() > delta {
+            let movement = delta_target.normalize() * delta;
+            transform.translation += movement.extend(0.0).xzy();
+            //Copy for ownership reasons
+            let y = transform.translation.y;
+            transform.look_at(waypoint.extend(y).xzy(), Vec3::Y);
+        } else {
+            // At current step
+            target.path_index += 1;
+        }
+    }
+}
+
+pub(super) fn target_death(
+    mut commands: Commands,
+    targets: Query<(Entity, &Health, &Target, &EnemyType, &Transform)>,
+    path: Res<TargetPath>,
+    mut target_death_event_writer: EventWriter<TargetDeathEvent>,
+) {
+    for (entity, health, target, enemy_type, transform) in &targets {
+        // Reached the goal this frame, `hurt_player` has already taken a life for it
+        if target.path_index >= path.waypoints.len() {
+            continue;
+        }
+        if health.value <= 0 {
+            target_death_event_writer.send(TargetDeathEvent {
+                position: transform.translation,
+                path_index: target.path_index,
+                enemy_type: *enemy_type,
+            });
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+pub(super) fn split_on_death(
+    mut commands: Commands,
+    mut death_events: EventReader<TargetDeathEvent>,
+    assets: Res<GameAssets>,
+) {
+    for event in death_events.iter() {
+        let Some(OnDeath::Split { into, count }) = event.enemy_type.on_death() else {
+            continue;
+        };
+
+        // Spread the children out a little so they don't stack on one spot
+        for i in 0..count {
+            let angle = i as f32 / count as f32 * std::f32::consts::TAU;
+            let offset = Vec3::new(angle.cos(), 0.0, angle.sin()) * 0.25;
+            spawn_target(
+                &mut commands,
+                &assets,
+                into,
+                event.position + offset,
+                event.path_index,
+            );
+        }
+    }
+}
+
+pub(super) fn hurt_player(
+    mut commands: Commands,
+    targets: Query<(Entity, &Target)>,
+    path: Res<TargetPath>,
+    mut player: Query<&mut Player>,
+    mut sfx: EventWriter<SfxEvent>,
+) {
+    for (entity, target) in &targets {
+        if target.path_index >= path.waypoints.len() {
+            commands.entity(entity).despawn_recursive();
+
+            sfx.send(SfxEvent::LifeLost);
+
+            let mut player = player.single_mut();
+            if player.health > 0 {
+                player.health -= 1;
+
+                if player.health == 0 {
+                    info!("GAME OVER");
+                    sfx.send(SfxEvent::GameOver);
+                }
+            }
+        }
+    }
+}
+
+pub(super) fn apply_auras(
+    mut commands: Commands,
+    mut auras: Query<(&mut Aura, &Transform)>,
+    mut targets: Query<(Entity, &Transform, &mut Health, Option<&mut Shield>), With<Target>>,
+    time: Res<Time>,
+) {
+    for (mut aura, aura_transform) in &mut auras {
+        aura.timer.tick(time.delta());
+        if !aura.timer.just_finished() {
+            continue;
+        }
+
+        for (entity, transform, mut health, shield) in &mut targets {
+            // The lethal hit has already been counted, don't bring it back
+            if health.value <= 0 {
+                continue;
+            }
+            if Vec3::distance(transform.translation, aura_transform.translation) > aura.radius {
+                continue;
+            }
+
+            match aura.kind {
+                AuraKind::Heal { amount } => health.heal(amount),
+                AuraKind::Shield { amount, max } => match shield {
+                    Some(mut shield) => shield.value = (shield.value + amount).min(max),
+                    None => {
+                        commands.entity(entity).insert(Shield {
+                            value: amount.min(max),
+                        });
+                    }
+                },
+            }
+        }
+    }
+}
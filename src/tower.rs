@@ -1,11 +1,28 @@
 use bevy::prelude::*;
 
+mod aura;
+mod beam;
 mod components;
+mod events;
+mod hitscan;
+mod hotkeys;
+mod info_panel;
+mod models;
+mod range;
 mod systems;
 mod ui;
 
+use aura::*;
+use beam::*;
 pub use components::*;
+pub use events::*;
+use hitscan::*;
+use hotkeys::*;
+use info_panel::*;
+pub use models::{compost_scene, laser_carrot_scene, pea_shooter_scene, pumpkin_patch_scene};
+use range::*;
 use systems::*;
+pub use systems::{spawn_tower_base, tower_shooting};
 use ui::*;
 
 use crate::GameState;
@@ -16,15 +33,66 @@ impl Plugin for TowerPlugin {
     fn build(&self, app: &mut App) {
         app.register_type::<Tower>()
             .register_type::<TowerButtonState>()
+            .register_type::<TowerRecord>()
+            .register_type::<TargetingMode>()
+            .register_type::<Beam>()
+            .register_type::<StatModifiers>()
+            .register_type::<TowerAura>()
+            .register_type::<Farm>()
+            .add_event::<TowerFiredEvent>()
+            .add_systems(
+                (apply_tower_auras, update_tower_stats)
+                    .chain()
+                    .before(tower_shooting)
+                    .before(aim_beams)
+                    .before(pulse_auras)
+                    .in_set(OnUpdate(GameState::Gameplay)),
+            )
             .add_systems(
                 (
                     tower_shooting,
+                    pulse_auras,
                     tower_button_clicked,
                     create_ui_on_selection,
                     grey_tower_buttons.after(create_ui_on_selection),
+                    tower_button_tooltips,
+                    update_range_indicator,
+                    record_tower_hits,
+                    record_tower_misses,
+                )
+                    .in_set(OnUpdate(GameState::Gameplay)),
+            )
+            .add_systems(
+                (
+                    create_info_panel_on_selection,
+                    update_info_panel.after(create_info_panel_on_selection),
+                    upgrade_button_clicked,
+                    sell_button_clicked,
+                    targeting_button_clicked,
                 )
                     .in_set(OnUpdate(GameState::Gameplay)),
             )
-            .add_system(despawn_towers.in_schedule(OnExit(GameState::Gameplay)));
+            .add_systems(
+                (
+                    hitscan_attacks.after(tower_shooting),
+                    fade_tracers,
+                    aim_beams,
+                    beam_damage.after(aim_beams),
+                )
+                    .in_set(OnUpdate(GameState::Gameplay)),
+            )
+            .add_systems(
+                (
+                    build_hotkeys,
+                    upgrade_and_sell_hotkeys,
+                    cycle_selection_hotkey,
+                )
+                    .in_set(OnUpdate(GameState::Gameplay)),
+            )
+            .add_system(spawn_range_indicator.in_schedule(OnEnter(GameState::Gameplay)))
+            .add_systems(
+                (despawn_towers, despawn_range_indicator, despawn_tracers)
+                    .in_schedule(OnExit(GameState::Gameplay)),
+            );
     }
 }
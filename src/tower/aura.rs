@@ -0,0 +1,54 @@
+use bevy::prelude::*;
+
+use crate::*;
+
+/// Refreshes the aura entries of every tower's modifier stack. Only towers that
+/// attack are buffed, and overlapping auras stack.
+pub(super) fn apply_tower_auras(
+    auras: Query<(Entity, &TowerAura, &Tower, &GlobalTransform)>,
+    mut towers: Query<(&mut StatModifiers, &TowerType, &GlobalTransform)>,
+) {
+    for (mut modifiers, _, _) in &mut towers {
+        modifiers.clear_auras();
+    }
+
+    for (source, aura, aura_tower, aura_transform) in &auras {
+        let modifier = aura.at_level(aura_tower.level);
+        for (mut modifiers, tower_type, transform) in &mut towers {
+            if tower_type.attack_kind().attacks()
+                && transform
+                    .translation()
+                    .distance(aura_transform.translation())
+                    < aura_tower.range
+            {
+                modifiers
+                    .stack
+                    .push((ModifierSource::Aura(source), modifier));
+            }
+        }
+    }
+}
+
+pub(super) fn update_tower_stats(mut towers: Query<(&mut Tower, &StatModifiers)>) {
+    for (mut tower, modifiers) in &mut towers {
+        tower.apply(modifiers.total());
+    }
+}
+
+/// Puffs of soil so it's visible which towers are feeding their neighbours.
+pub(super) fn pulse_auras(
+    mut auras: Query<(&mut Tower, &TowerType, &GlobalTransform), With<TowerAura>>,
+    mut particle_events: EventWriter<ParticleEvent>,
+    time: Res<Time>,
+) {
+    for (mut tower, tower_type, transform) in &mut auras {
+        tower.shooting_timer.tick(time.delta());
+        if tower.shooting_timer.just_finished() {
+            particle_events.send(ParticleEvent {
+                position: transform.translation() + tower.bullet_offset,
+                direction: Vec3::Y,
+                effect: ParticleEffect::Splat(tower_type.splat_color()),
+            });
+        }
+    }
+}
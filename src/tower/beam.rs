@@ -0,0 +1,126 @@
+use bevy::prelude::*;
+
+use crate::*;
+
+use super::systems::choose_target;
+
+const BEAM_WIDTH: f32 = 0.05;
+
+/// Transform stretching a unit cube from `start` to `end`, `width` across.
+pub(super) fn segment_transform(start: Vec3, end: Vec3, width: f32) -> Transform {
+    let offset = end - start;
+    if offset.length_squared() < f32::EPSILON {
+        return Transform::from_translation(start).with_scale(Vec3::ZERO);
+    }
+
+    Transform {
+        translation: (start + end) / 2.0,
+        rotation: Quat::from_rotation_arc(Vec3::Z, offset.normalize()),
+        scale: Vec3::new(width, width, offset.length()),
+    }
+}
+
+/// Keeps each beam on its target while it stays in range, otherwise picks a new one.
+pub(super) fn aim_beams(
+    mut towers: Query<(
+        Entity,
+        &Tower,
+        &mut Beam,
+        &TowerType,
+        &TargetingMode,
+        &GlobalTransform,
+        &Children,
+    )>,
+    targets: Query<(Entity, &GlobalTransform, &Target, &Health)>,
+    mut visuals: Query<(&mut Transform, &mut Visibility), With<BeamVisual>>,
+    path: Res<TargetPath>,
+    mut fired_events: EventWriter<TowerFiredEvent>,
+    time: Res<Time>,
+) {
+    for (tower_entity, tower, mut beam, tower_type, targeting, transform, children) in &mut towers {
+        let start = transform.translation() + tower.bullet_offset;
+        let held = beam
+            .target
+            .and_then(|target| targets.get(target).ok())
+            .map(|(target, target_transform, ..)| (target, target_transform.translation()))
+            .filter(|(_, position)| position.distance(start) < tower.range);
+
+        let aimed = match held {
+            Some(held) => {
+                beam.time_on_target += time.delta_seconds();
+                Some(held)
+            }
+            None => {
+                let chosen = choose_target(&targets, &path, start, tower.range, *targeting);
+                beam.target = chosen.map(|(target, _)| target);
+                beam.time_on_target = 0.0;
+
+                if let Some((target, position)) = chosen {
+                    fired_events.send(TowerFiredEvent {
+                        tower: tower_entity,
+                        tower_type: *tower_type,
+                        target,
+                        position: start,
+                        direction: position - start,
+                        damage: tower.damage,
+                        range: tower.range,
+                    });
+                }
+                chosen
+            }
+        };
+
+        let mut visuals = visuals.iter_many_mut(children);
+        while let Some((mut visual_transform, mut visibility)) = visuals.fetch_next() {
+            let Some((_, end)) = aimed else {
+                *visibility = Visibility::Hidden;
+                continue;
+            };
+
+            // Thickens as the damage ramps up
+            let width = BEAM_WIDTH * beam.multiplier().sqrt();
+            let world = segment_transform(start, end, width);
+            *visual_transform = Transform::from_matrix(
+                transform.compute_matrix().inverse() * world.compute_matrix(),
+            );
+            *visibility = Visibility::Inherited;
+        }
+    }
+}
+
+pub(super) fn beam_damage(
+    mut towers: Query<(Entity, &mut Tower, &Beam, &TowerType)>,
+    mut targets: Query<(&mut Health, Option<&mut Shield>, &GlobalTransform), With<Target>>,
+    mut hit_events: EventWriter<BulletHitEvent>,
+    time: Res<Time>,
+) {
+    let mut rng = rand::thread_rng();
+
+    for (tower_entity, mut tower, beam, tower_type) in &mut towers {
+        tower.shooting_timer.tick(time.delta());
+        if !tower.shooting_timer.just_finished() {
+            continue;
+        }
+        let Some(target) = beam.target else {
+            continue;
+        };
+        let Ok((mut health, shield, target_transform)) = targets.get_mut(target) else {
+            continue;
+        };
+
+        let ramped = (tower.damage as f32 * beam.multiplier()).round() as i32;
+        let shot = tower_type.bullet(tower_entity, Vec3::ZERO, ramped);
+        let (damage, crit) = shot.roll_damage(&mut rng);
+        let lethal = health.take_damage(shield.map(|shield| shield.into_inner()), damage);
+
+        hit_events.send(BulletHitEvent {
+            source: tower_entity,
+            target,
+            position: target_transform.translation(),
+            damage,
+            damage_type: shot.damage_type,
+            crit,
+            lethal,
+        });
+    }
+}
@@ -1,18 +1,337 @@
+use std::{fmt, time::Duration};
+
 use bevy::prelude::*;
 
 use crate::*;
 
+/// A placed tower. `shooting_timer`, `range` and `damage` are the effective stats,
+/// recomputed every frame from `base` and the tower's `StatModifiers`.
 #[derive(Component, Default, Reflect)]
 #[reflect(Component)]
 pub struct Tower {
     pub shooting_timer: Timer,
     pub bullet_offset: Vec3,
     pub range: f32,
+    pub damage: i32,
+    pub level: u32,
+    pub base: TowerStats,
+}
+
+pub const MAX_TOWER_LEVEL: u32 = 3;
+
+impl Tower {
+    pub fn new(base: TowerStats, bullet_offset: Vec3) -> Self {
+        Self {
+            shooting_timer: Timer::from_seconds(base.fire_interval, TimerMode::Repeating),
+            bullet_offset,
+            range: base.range,
+            damage: base.damage,
+            level: 1,
+            base,
+        }
+    }
+
+    /// Raises the level and the base stats a tower of `kind` makes use of.
+    pub fn upgrade(&mut self, kind: AttackKind) {
+        self.level += 1;
+        match kind {
+            AttackKind::Projectile | AttackKind::Hitscan | AttackKind::Beam => {
+                self.base.damage += 1;
+                self.base.range += 0.5;
+                self.base.fire_interval *= 0.85;
+            }
+            // Wider aura, its strength grows with the level in `TowerAura::at_level`
+            AttackKind::Support => self.base.range += 0.5,
+            // Only the harvest grows, see `Farm::harvest_at`
+            AttackKind::Income => {}
+        }
+    }
+
+    /// Sets the effective stats to `base` with `modifier` applied on top.
+    pub fn apply(&mut self, modifier: StatModifier) {
+        let stats = self.base.modified(modifier);
+        self.range = stats.range;
+        self.damage = stats.damage;
+
+        let interval = Duration::from_secs_f32(stats.fire_interval);
+        if self.shooting_timer.duration() != interval {
+            self.shooting_timer.set_duration(interval);
+        }
+    }
+
+    /// Whether anything is currently changing the tower's stats.
+    pub fn is_modified(&self) -> bool {
+        self.range != self.base.range
+            || self.damage != self.base.damage
+            || self.shooting_timer.duration() != Duration::from_secs_f32(self.base.fire_interval)
+    }
+}
+
+/// Stats of a tower before modifiers, upgrades included.
+#[derive(Clone, Copy, Debug, Default, Reflect, FromReflect)]
+pub struct TowerStats {
+    pub fire_interval: f32,
+    pub range: f32,
+    pub damage: i32,
+}
+
+impl TowerStats {
+    pub fn modified(&self, modifier: StatModifier) -> Self {
+        Self {
+            fire_interval: self.fire_interval / modifier.fire_rate,
+            range: self.range * modifier.range,
+            damage: (self.damage as f32 * modifier.damage).round() as i32,
+        }
+    }
+}
+
+/// Multipliers on a tower's stats, above 1.0 for buffs and below for debuffs.
+#[derive(Clone, Copy, Debug, PartialEq, Reflect, FromReflect)]
+pub struct StatModifier {
+    pub fire_rate: f32,
+    pub range: f32,
+    pub damage: f32,
+}
+
+impl Default for StatModifier {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl StatModifier {
+    pub const IDENTITY: Self = Self {
+        fire_rate: 1.0,
+        range: 1.0,
+        damage: 1.0,
+    };
+
+    /// Both modifiers applied one after the other.
+    pub fn combine(self, other: Self) -> Self {
+        Self {
+            fire_rate: self.fire_rate * other.fire_rate,
+            range: self.range * other.range,
+            damage: self.damage * other.damage,
+        }
+    }
+}
+
+impl fmt::Display for StatModifier {
+    /// Lists the changed stats, e.g. "+15% fire rate, +20% damage".
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let stats = [
+            (self.fire_rate, "fire rate"),
+            (self.range, "range"),
+            (self.damage, "damage"),
+        ];
+        let changes: Vec<String> = stats
+            .iter()
+            .filter(|(multiplier, _)| *multiplier != 1.0)
+            .map(|(multiplier, name)| format!("{:+.0}% {}", (multiplier - 1.0) * 100.0, name))
+            .collect();
+
+        if changes.is_empty() {
+            write!(f, "no change")
+        } else {
+            write!(f, "{}", changes.join(", "))
+        }
+    }
+}
+
+/// Where an entry of a `StatModifiers` stack came from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Reflect, FromReflect)]
+pub enum ModifierSource {
+    /// Support tower the buffed tower stands next to
+    Aura(Entity),
+}
+
+/// Modifiers currently acting on a tower. Each source refreshes its own entries.
+#[derive(Component, Default, Reflect)]
+#[reflect(Component)]
+pub struct StatModifiers {
+    pub stack: Vec<(ModifierSource, StatModifier)>,
+}
+
+impl StatModifiers {
+    pub fn total(&self) -> StatModifier {
+        self.stack
+            .iter()
+            .fold(StatModifier::IDENTITY, |total, (_, modifier)| {
+                total.combine(*modifier)
+            })
+    }
+
+    pub fn clear_auras(&mut self) {
+        self.stack
+            .retain(|(source, _)| !matches!(source, ModifierSource::Aura(_)));
+    }
+}
+
+/// Buff a support tower gives to the other towers within its range.
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct TowerAura {
+    pub modifier: StatModifier,
+}
+
+impl TowerAura {
+    /// The buff at tower `level`, each level past the first adding half the base bonus.
+    pub fn at_level(&self, level: u32) -> StatModifier {
+        let scale = 1.0 + 0.5 * level.saturating_sub(1) as f32;
+        let boost = |multiplier: f32| 1.0 + (multiplier - 1.0) * scale;
+        StatModifier {
+            fire_rate: boost(self.modifier.fire_rate),
+            range: boost(self.modifier.range),
+            damage: boost(self.modifier.damage),
+        }
+    }
+}
+
+/// Running totals for a placed tower, shown in the info panel.
+#[derive(Component, Default, Reflect)]
+#[reflect(Component)]
+pub struct TowerRecord {
+    pub kills: u32,
+    pub damage_dealt: u32,
+    pub invested: u32,
+    pub hits: u32,
+    pub misses: u32,
+    /// Money paid out by a farm tower
+    pub harvested: u32,
+}
+
+impl TowerRecord {
+    /// Fraction of finished shots that hit, `None` before the first one lands or misses.
+    pub fn accuracy(&self) -> Option<f32> {
+        let shots = self.hits + self.misses;
+        (shots > 0).then(|| self.hits as f32 / shots as f32)
+    }
+
+    pub fn sell_value(&self) -> u32 {
+        self.invested * 7 / 10
+    }
+}
+
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub enum TargetingMode {
+    /// Furthest along the path
+    #[default]
+    First,
+    Last,
+    Closest,
+    Strongest,
 }
 
+impl TargetingMode {
+    pub fn next(&self) -> Self {
+        match self {
+            TargetingMode::First => TargetingMode::Last,
+            TargetingMode::Last => TargetingMode::Closest,
+            TargetingMode::Closest => TargetingMode::Strongest,
+            TargetingMode::Strongest => TargetingMode::First,
+        }
+    }
+}
+
+/// Empty plot a tower can be built on.
+#[derive(Component)]
+pub struct TowerBase;
+
+/// Ground disc previewing a tower's range.
+#[derive(Component)]
+pub struct RangeIndicator;
+
 #[derive(Component)]
 pub struct TowerUIRoot;
 
+#[derive(Component)]
+pub struct TowerTooltip;
+
+#[derive(Component)]
+pub struct TowerInfoRoot;
+
+#[derive(Component)]
+pub struct TowerInfoText;
+
+#[derive(Component)]
+pub struct UpgradeButton;
+
+#[derive(Component)]
+pub struct SellButton;
+
+#[derive(Component)]
+pub struct TargetingButton;
+
+/// How a tower delivers its damage.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AttackKind {
+    /// Fires a `Bullet` that has to fly to the target
+    Projectile,
+    /// Damages the target instantly and leaves a brief tracer
+    Hitscan,
+    /// Holds a continuous `Beam` on one target, see `Tower::shooting_timer` for the tick rate
+    Beam,
+    /// Never attacks, buffs nearby towers with its `TowerAura` instead
+    Support,
+    /// Never attacks, its `Farm` pays out after every cleared wave instead
+    Income,
+}
+
+impl AttackKind {
+    /// Whether towers of this kind deal damage, and so have use for damage,
+    /// range and fire rate buffs.
+    pub fn attacks(&self) -> bool {
+        matches!(
+            self,
+            AttackKind::Projectile | AttackKind::Hitscan | AttackKind::Beam
+        )
+    }
+}
+
+/// Continuous attack state of a beam tower.
+#[derive(Component, Default, Reflect)]
+#[reflect(Component)]
+pub struct Beam {
+    pub target: Option<Entity>,
+    /// Seconds the beam has stayed on `target`
+    pub time_on_target: f32,
+    /// Damage multiplier gained per second on the same target
+    pub ramp_per_second: f32,
+    pub max_multiplier: f32,
+}
+
+impl Beam {
+    pub fn multiplier(&self) -> f32 {
+        (1.0 + self.ramp_per_second * self.time_on_target).min(self.max_multiplier)
+    }
+}
+
+/// Stretched mesh drawn from a beam tower to its target.
+#[derive(Component)]
+pub struct BeamVisual;
+
+/// Short-lived line left by a hitscan shot.
+#[derive(Component)]
+pub struct Tracer {
+    pub timer: Timer,
+}
+
+/// Money a farm tower pays the player every time a wave is cleared.
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct Farm {
+    pub harvest: u32,
+    /// Added to `harvest` for every level past the first
+    pub harvest_per_level: u32,
+}
+
+impl Farm {
+    pub fn harvest_at(&self, level: u32) -> u32 {
+        self.harvest + self.harvest_per_level * level.saturating_sub(1)
+    }
+}
+
 #[derive(Component, Default, Reflect)]
 #[reflect(Component)]
 pub struct TowerButtonState {
@@ -21,72 +340,407 @@ pub struct TowerButtonState {
 }
 
 // Migration guide from`Inspectable` to `Reflect`: https://shorturl.at/gjq09
-#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Reflect)]
 #[reflect(Component)]
 pub enum TowerType {
     #[default]
     Tomato,
     Potato,
     Cabbage,
+    PeaShooter,
+    LaserCarrot,
+    Compost,
+    PumpkinPatch,
 }
 
 impl TowerType {
-    pub(super) fn get_tower(&self, assets: &GameAssets) -> (Handle<Scene>, Tower) {
+    pub const ALL: [TowerType; 7] = [
+        TowerType::Tomato,
+        TowerType::Potato,
+        TowerType::Cabbage,
+        TowerType::PeaShooter,
+        TowerType::LaserCarrot,
+        TowerType::Compost,
+        TowerType::PumpkinPatch,
+    ];
+
+    pub fn cost(&self) -> u32 {
         match self {
-            TowerType::Tomato => (
-                assets.tomato_tower_scene.clone(),
-                Tower {
-                    shooting_timer: Timer::from_seconds(0.5, TimerMode::Repeating),
-                    bullet_offset: Vec3::new(0.0, 0.6, 0.0),
-                    range: 6.5,
-                },
-            ),
-            TowerType::Potato => (
-                assets.potato_tower_scene.clone(),
-                Tower {
-                    shooting_timer: Timer::from_seconds(0.5, TimerMode::Repeating),
-                    bullet_offset: Vec3::new(0.0, 0.6, 0.0),
-                    range: 6.5,
-                },
-            ),
-            TowerType::Cabbage => (
-                assets.cabbage_tower_scene.clone(),
-                Tower {
-                    shooting_timer: Timer::from_seconds(0.5, TimerMode::Repeating),
-                    bullet_offset: Vec3::new(0.0, 0.6, 0.0),
-                    range: 6.5,
+            TowerType::Tomato => 50,
+            TowerType::Potato => 80,
+            TowerType::Cabbage => 110,
+            TowerType::PeaShooter => 90,
+            TowerType::LaserCarrot => 130,
+            TowerType::Compost => 120,
+            TowerType::PumpkinPatch => 100,
+        }
+    }
+
+    pub fn attack_kind(&self) -> AttackKind {
+        match self {
+            TowerType::Tomato | TowerType::Potato | TowerType::Cabbage => AttackKind::Projectile,
+            TowerType::PeaShooter => AttackKind::Hitscan,
+            TowerType::LaserCarrot => AttackKind::Beam,
+            TowerType::Compost => AttackKind::Support,
+            TowerType::PumpkinPatch => AttackKind::Income,
+        }
+    }
+
+    pub fn farm(&self) -> Option<Farm> {
+        match self.attack_kind() {
+            AttackKind::Income => Some(Farm {
+                harvest: 25,
+                harvest_per_level: 15,
+            }),
+            _ => None,
+        }
+    }
+
+    pub fn aura(&self) -> Option<TowerAura> {
+        match self.attack_kind() {
+            AttackKind::Support => Some(TowerAura {
+                modifier: StatModifier {
+                    fire_rate: 1.15,
+                    range: 1.1,
+                    damage: 1.2,
                 },
-            ),
+            }),
+            _ => None,
+        }
+    }
+
+    pub fn beam(&self) -> Option<Beam> {
+        match self.attack_kind() {
+            AttackKind::Beam => Some(Beam {
+                ramp_per_second: 0.75,
+                max_multiplier: 4.0,
+                ..default()
+            }),
+            _ => None,
+        }
+    }
+
+    /// Price of taking a tower of this type from `level` to `level + 1`.
+    pub fn upgrade_cost(&self, level: u32) -> u32 {
+        self.cost() / 2 * level
+    }
+
+    /// Color of the juice left behind when this tower's vegetable hits something.
+    pub fn splat_color(&self) -> Color {
+        match self {
+            TowerType::Tomato => Color::rgb(0.85, 0.15, 0.1),
+            TowerType::Potato => Color::rgb(0.8, 0.65, 0.4),
+            TowerType::Cabbage => Color::rgb(0.45, 0.8, 0.3),
+            TowerType::PeaShooter => Color::rgb(0.4, 0.75, 0.25),
+            TowerType::LaserCarrot => Color::rgb(1.0, 0.55, 0.1),
+            TowerType::Compost => Color::rgb(0.4, 0.28, 0.15),
+            TowerType::PumpkinPatch => Color::rgb(0.95, 0.55, 0.1),
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            TowerType::Tomato => "Tomato Tower",
+            TowerType::Potato => "Potato Tower",
+            TowerType::Cabbage => "Cabbage Tower",
+            TowerType::PeaShooter => "Pea Shooter",
+            TowerType::LaserCarrot => "Laser Carrot",
+            TowerType::Compost => "Compost Heap",
+            TowerType::PumpkinPatch => "Pumpkin Patch",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            TowerType::Tomato => {
+                "Lobs ripe tomatoes whose acidic juice stings. Cheap and cheerful."
+            }
+            TowerType::Potato => {
+                "Fires starchy spuds at high velocity. Rarely misses a moving target."
+            }
+            TowerType::Cabbage => "Hurls heavy cabbages that crush whatever they land on.",
+            TowerType::PeaShooter => {
+                "Snipes a single pea so fast it lands the moment it's fired. Long range."
+            }
+            TowerType::LaserCarrot => {
+                "Focuses a beam on one target, burning hotter the longer it holds on."
+            }
+            TowerType::Compost => {
+                "Enriches the soil around it. Nearby towers fire faster, reach further and hit harder."
+            }
+            TowerType::PumpkinPatch => {
+                "Grows pumpkins to sell after every wave. Defends nothing, but pays for itself."
+            }
+        }
+    }
+
+    pub fn tower(&self) -> Tower {
+        let base = match self {
+            TowerType::Tomato => TowerStats {
+                fire_interval: 0.5,
+                range: 6.5,
+                damage: 1,
+            },
+            TowerType::Potato => TowerStats {
+                fire_interval: 0.5,
+                range: 6.5,
+                damage: 1,
+            },
+            TowerType::Cabbage => TowerStats {
+                fire_interval: 0.5,
+                range: 6.5,
+                damage: 2,
+            },
+            TowerType::PeaShooter => TowerStats {
+                fire_interval: 1.2,
+                range: 9.0,
+                damage: 3,
+            },
+            TowerType::LaserCarrot => TowerStats {
+                fire_interval: 0.25,
+                range: 5.0,
+                damage: 1,
+            },
+            // Range is the aura radius, the interval only paces its soil puffs
+            TowerType::Compost => TowerStats {
+                fire_interval: 1.0,
+                range: 3.5,
+                damage: 0,
+            },
+            // Only harvests, see `TowerType::farm`
+            TowerType::PumpkinPatch => TowerStats {
+                fire_interval: 1.0,
+                range: 0.0,
+                damage: 0,
+            },
+        };
+        Tower::new(base, Vec3::new(0.0, 0.6, 0.0))
+    }
+
+    pub fn bullet(&self, owner: Entity, direction: Vec3, damage: i32) -> Bullet {
+        match self {
+            TowerType::Tomato => Bullet {
+                owner,
+                direction,
+                speed: 3.5,
+                damage,
+                damage_type: DamageType::Acid,
+                crit_chance: 0.1,
+                ..default()
+            },
+            TowerType::Potato => Bullet {
+                owner,
+                direction,
+                speed: 6.5,
+                damage,
+                damage_type: DamageType::Physical,
+                crit_chance: 0.1,
+                ..default()
+            },
+            TowerType::Cabbage => Bullet {
+                owner,
+                direction,
+                speed: 2.5,
+                damage,
+                damage_type: DamageType::Nature,
+                crit_chance: 0.1,
+                ..default()
+            },
+            TowerType::PeaShooter => Bullet {
+                owner,
+                direction,
+                damage,
+                damage_type: DamageType::Physical,
+                crit_chance: 0.25,
+                ..default()
+            },
+            TowerType::LaserCarrot => Bullet {
+                owner,
+                direction,
+                damage,
+                damage_type: DamageType::Energy,
+                crit_chance: 0.0,
+                ..default()
+            },
+            TowerType::Compost | TowerType::PumpkinPatch => Bullet {
+                owner,
+                direction,
+                damage,
+                damage_type: DamageType::Nature,
+                crit_chance: 0.0,
+                ..default()
+            },
         }
     }
 
-    pub(super) fn get_bullet(
+    pub(super) fn get_tower(&self, assets: &GameAssets) -> (Handle<Scene>, Tower) {
+        let scene = match self {
+            TowerType::Tomato => assets.tomato_tower_scene.clone(),
+            TowerType::Potato => assets.potato_tower_scene.clone(),
+            TowerType::Cabbage => assets.cabbage_tower_scene.clone(),
+            TowerType::PeaShooter => assets.pea_shooter_tower_scene.clone(),
+            TowerType::LaserCarrot => assets.laser_carrot_tower_scene.clone(),
+            TowerType::Compost => assets.compost_tower_scene.clone(),
+            TowerType::PumpkinPatch => assets.pumpkin_patch_tower_scene.clone(),
+        };
+        (scene, self.tower())
+    }
+
+    pub fn get_bullet(
         &self,
+        owner: Entity,
         direction: Vec3,
+        damage: i32,
         assets: &GameAssets,
     ) -> (Handle<Scene>, Bullet) {
-        match self {
-            TowerType::Tomato => (
-                assets.tomato_scene.clone(),
-                Bullet {
-                    direction,
-                    speed: 3.5,
-                },
-            ),
-            TowerType::Potato => (
-                assets.potato_scene.clone(),
-                Bullet {
-                    direction,
-                    speed: 6.5,
-                },
-            ),
-            TowerType::Cabbage => (
-                assets.cabbage_scene.clone(),
-                Bullet {
-                    direction,
-                    speed: 2.5,
-                },
-            ),
+        let scene = match self {
+            TowerType::Tomato => assets.tomato_scene.clone(),
+            TowerType::Potato => assets.potato_scene.clone(),
+            TowerType::Cabbage => assets.cabbage_scene.clone(),
+            // Never fired as projectiles
+            TowerType::PeaShooter
+            | TowerType::LaserCarrot
+            | TowerType::Compost
+            | TowerType::PumpkinPatch => Handle::default(),
+        };
+        (scene, self.bullet(owner, direction, damage))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn boost(fire_rate: f32, range: f32, damage: f32) -> StatModifier {
+        StatModifier {
+            fire_rate,
+            range,
+            damage,
         }
     }
+
+    #[test]
+    fn empty_stack_leaves_stats_alone() {
+        let mut tower = TowerType::Tomato.tower();
+        tower.apply(StatModifiers::default().total());
+        assert!(!tower.is_modified());
+        assert_eq!(tower.range, tower.base.range);
+    }
+
+    #[test]
+    fn modifiers_multiply_together() {
+        let modifiers = StatModifiers {
+            stack: vec![
+                (
+                    ModifierSource::Aura(Entity::PLACEHOLDER),
+                    boost(1.5, 1.0, 2.0),
+                ),
+                (
+                    ModifierSource::Aura(Entity::PLACEHOLDER),
+                    boost(2.0, 0.5, 1.0),
+                ),
+            ],
+        };
+        assert_eq!(modifiers.total(), boost(3.0, 0.5, 2.0));
+    }
+
+    #[test]
+    fn apply_recomputes_from_base_every_time() {
+        let mut tower = Tower::new(
+            TowerStats {
+                fire_interval: 1.0,
+                range: 4.0,
+                damage: 3,
+            },
+            Vec3::ZERO,
+        );
+
+        tower.apply(boost(2.0, 1.5, 2.0));
+        assert_eq!(tower.shooting_timer.duration().as_secs_f32(), 0.5);
+        assert_eq!(tower.range, 6.0);
+        assert_eq!(tower.damage, 6);
+        assert!(tower.is_modified());
+
+        // Buffs don't compound from one frame to the next
+        tower.apply(boost(2.0, 1.5, 2.0));
+        assert_eq!(tower.range, 6.0);
+
+        tower.apply(StatModifier::IDENTITY);
+        assert!(!tower.is_modified());
+    }
+
+    #[test]
+    fn upgrades_and_buffs_compose() {
+        let mut tower = TowerType::Tomato.tower();
+        tower.upgrade(AttackKind::Projectile);
+        tower.apply(boost(1.0, 2.0, 1.0));
+        assert_eq!(tower.range, (6.5 + 0.5) * 2.0);
+        assert_eq!(tower.damage, 2);
+    }
+
+    #[test]
+    fn upgrades_only_touch_stats_the_tower_uses() {
+        let mut compost = TowerType::Compost.tower();
+        compost.upgrade(AttackKind::Support);
+        assert_eq!(compost.base.damage, 0);
+        assert_eq!(compost.base.fire_interval, 1.0);
+        assert_eq!(compost.base.range, 4.0);
+
+        let mut farm = TowerType::PumpkinPatch.tower();
+        farm.upgrade(AttackKind::Income);
+        assert_eq!(farm.level, 2);
+        assert_eq!(farm.base.range, 0.0);
+    }
+
+    #[test]
+    fn clearing_auras_empties_the_stack_of_aura_entries() {
+        let mut modifiers = StatModifiers {
+            stack: vec![(
+                ModifierSource::Aura(Entity::PLACEHOLDER),
+                boost(2.0, 2.0, 2.0),
+            )],
+        };
+        modifiers.clear_auras();
+        assert!(modifiers.stack.is_empty());
+        assert_eq!(modifiers.total(), StatModifier::IDENTITY);
+    }
+
+    #[test]
+    fn aura_bonus_grows_by_half_per_level() {
+        let aura = TowerAura {
+            modifier: boost(1.2, 1.0, 1.4),
+        };
+        let at_three = aura.at_level(3);
+        assert!((at_three.fire_rate - 1.4).abs() < 1e-5);
+        assert_eq!(at_three.range, 1.0);
+        assert!((at_three.damage - 1.8).abs() < 1e-5);
+    }
+
+    #[test]
+    fn modifier_lists_only_changed_stats() {
+        assert_eq!(
+            boost(1.15, 1.0, 0.8).to_string(),
+            "+15% fire rate, -20% damage"
+        );
+        assert_eq!(StatModifier::IDENTITY.to_string(), "no change");
+    }
+
+    #[test]
+    fn only_attacking_kinds_take_combat_buffs() {
+        assert!(AttackKind::Projectile.attacks());
+        assert!(AttackKind::Beam.attacks());
+        assert!(!AttackKind::Support.attacks());
+    }
+
+    #[test]
+    fn harvest_grows_with_every_level_past_the_first() {
+        let farm = Farm {
+            harvest: 25,
+            harvest_per_level: 15,
+        };
+        assert_eq!(farm.harvest_at(1), 25);
+        assert_eq!(farm.harvest_at(3), 55);
+        // Level is never 0 in play, but it shouldn't underflow
+        assert_eq!(farm.harvest_at(0), 25);
+    }
 }
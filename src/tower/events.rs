@@ -0,0 +1,14 @@
+use bevy::prelude::*;
+
+use super::components::TowerType;
+
+pub struct TowerFiredEvent {
+    pub tower: Entity,
+    pub tower_type: TowerType,
+    pub target: Entity,
+    /// Where the shot left the tower
+    pub position: Vec3,
+    pub direction: Vec3,
+    pub damage: i32,
+    pub range: f32,
+}
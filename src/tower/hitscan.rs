@@ -0,0 +1,82 @@
+use bevy::{pbr::NotShadowCaster, prelude::*};
+
+use crate::*;
+
+use super::beam::segment_transform;
+
+const TRACER_SECONDS: f32 = 0.12;
+const TRACER_WIDTH: f32 = 0.04;
+
+pub(super) fn hitscan_attacks(
+    mut commands: Commands,
+    mut fired_events: EventReader<TowerFiredEvent>,
+    mut targets: Query<(&mut Health, Option<&mut Shield>, &GlobalTransform), With<Target>>,
+    mut hit_events: EventWriter<BulletHitEvent>,
+    assets: Res<GameAssets>,
+) {
+    let mut rng = rand::thread_rng();
+
+    for event in fired_events
+        .iter()
+        .filter(|event| event.tower_type.attack_kind() == AttackKind::Hitscan)
+    {
+        let Ok((mut health, shield, target_transform)) = targets.get_mut(event.target) else {
+            continue;
+        };
+        let target_position = target_transform.translation();
+
+        let shot = event
+            .tower_type
+            .bullet(event.tower, event.direction, event.damage);
+        let (damage, crit) = shot.roll_damage(&mut rng);
+        let lethal = health.take_damage(shield.map(|shield| shield.into_inner()), damage);
+
+        hit_events.send(BulletHitEvent {
+            source: event.tower,
+            target: event.target,
+            position: target_position,
+            damage,
+            damage_type: shot.damage_type,
+            crit,
+            lethal,
+        });
+
+        commands.spawn((
+            PbrBundle {
+                mesh: assets.beam_mesh.clone(),
+                material: assets.tracer_material.clone(),
+                transform: segment_transform(event.position, target_position, TRACER_WIDTH),
+                ..default()
+            },
+            Tracer {
+                timer: Timer::from_seconds(TRACER_SECONDS, TimerMode::Once),
+            },
+            NotShadowCaster,
+            Name::new("Tracer"),
+        ));
+    }
+}
+
+pub(super) fn fade_tracers(
+    mut commands: Commands,
+    mut tracers: Query<(Entity, &mut Tracer, &mut Transform)>,
+    time: Res<Time>,
+) {
+    for (entity, mut tracer, mut transform) in &mut tracers {
+        tracer.timer.tick(time.delta());
+        if tracer.timer.finished() {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        }
+
+        let width = TRACER_WIDTH * tracer.timer.percent_left();
+        transform.scale.x = width;
+        transform.scale.y = width;
+    }
+}
+
+pub(super) fn despawn_tracers(mut commands: Commands, tracers: Query<Entity, With<Tracer>>) {
+    for entity in &tracers {
+        commands.entity(entity).despawn_recursive();
+    }
+}
@@ -0,0 +1,121 @@
+use bevy::prelude::*;
+use bevy_mod_picking::*;
+
+use crate::*;
+
+use super::systems::{build_tower, sell_tower, upgrade_tower};
+
+pub(super) fn build_hotkeys(
+    mut commands: Commands,
+    actions: ActionInput,
+    bases: Query<(Entity, &Selection, &Transform), With<TowerBase>>,
+    mut player: Query<&mut Player>,
+    mut sfx: EventWriter<SfxEvent>,
+    assets: Res<GameAssets>,
+) {
+    let tower_type = [
+        (Action::BuildTomato, TowerType::Tomato),
+        (Action::BuildPotato, TowerType::Potato),
+        (Action::BuildCabbage, TowerType::Cabbage),
+        (Action::BuildPeaShooter, TowerType::PeaShooter),
+        (Action::BuildLaserCarrot, TowerType::LaserCarrot),
+        (Action::BuildCompost, TowerType::Compost),
+        (Action::BuildPumpkinPatch, TowerType::PumpkinPatch),
+    ]
+    .into_iter()
+    .find(|(action, _)| actions.just_pressed(*action))
+    .map(|(_, tower_type)| tower_type);
+
+    let Some(tower_type) = tower_type else {
+        return;
+    };
+    let mut player = player.single_mut();
+
+    for (entity, selection, transform) in &bases {
+        if selection.selected() {
+            build_tower(
+                &mut commands,
+                &assets,
+                &mut player,
+                &mut sfx,
+                entity,
+                transform.translation,
+                tower_type,
+            );
+        }
+    }
+}
+
+pub(super) fn upgrade_and_sell_hotkeys(
+    mut commands: Commands,
+    actions: ActionInput,
+    mut towers: Query<(
+        Entity,
+        &Selection,
+        &mut Tower,
+        &TowerType,
+        &mut TowerRecord,
+        &Transform,
+    )>,
+    mut player: Query<&mut Player>,
+    mut sfx: EventWriter<SfxEvent>,
+    assets: Res<GameAssets>,
+) {
+    let upgrade = actions.just_pressed(Action::Upgrade);
+    let sell = actions.just_pressed(Action::Sell);
+    if !upgrade && !sell {
+        return;
+    }
+    let mut player = player.single_mut();
+
+    for (entity, selection, mut tower, tower_type, mut record, transform) in &mut towers {
+        if !selection.selected() {
+            continue;
+        }
+
+        if sell {
+            sell_tower(
+                &mut commands,
+                &assets,
+                &mut player,
+                &mut sfx,
+                entity,
+                &record,
+                transform.translation,
+            );
+        } else {
+            upgrade_tower(&mut player, &mut tower, *tower_type, &mut record);
+        }
+    }
+}
+
+/// Moves the selection to the next empty tower base, ordered left to right.
+pub(super) fn cycle_selection_hotkey(
+    actions: ActionInput,
+    mut selectables: Query<(Entity, &mut Selection, Option<&TowerBase>, &Transform)>,
+) {
+    if !actions.just_pressed(Action::CycleSelection) {
+        return;
+    }
+
+    let mut bases: Vec<(Entity, bool, Vec3)> = selectables
+        .iter()
+        .filter(|(_, _, base, _)| base.is_some())
+        .map(|(entity, selection, _, transform)| {
+            (entity, selection.selected(), transform.translation)
+        })
+        .collect();
+    if bases.is_empty() {
+        return;
+    }
+    bases.sort_by(|(_, _, a), (_, _, b)| a.x.total_cmp(&b.x).then(a.z.total_cmp(&b.z)));
+
+    let next = match bases.iter().position(|(_, selected, _)| *selected) {
+        Some(current) => bases[(current + 1) % bases.len()].0,
+        None => bases[0].0,
+    };
+
+    for (entity, mut selection, _, _) in &mut selectables {
+        selection.set_selected(entity == next);
+    }
+}
@@ -0,0 +1,280 @@
+use bevy::{ecs::query::QuerySingleError, prelude::*};
+use bevy_mod_picking::*;
+
+use crate::*;
+
+use super::systems::{sell_tower, upgrade_tower};
+
+pub(super) fn create_info_panel_on_selection(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    towers: Query<&Selection, With<Tower>>,
+    root: Query<Entity, With<TowerInfoRoot>>,
+) {
+    let tower_selected = towers.iter().any(|selection| selection.selected());
+
+    match root.get_single() {
+        Ok(root) => {
+            if !tower_selected {
+                commands.entity(root).despawn_recursive();
+            }
+        }
+        Err(QuerySingleError::NoEntities(..)) => {
+            if tower_selected {
+                create_info_panel(&mut commands, &asset_server);
+            }
+        }
+        _ => unreachable!("Too many Tower Info Roots!"),
+    }
+}
+
+fn create_info_panel(commands: &mut Commands, asset_server: &AssetServer) {
+    let font = asset_server.load("FiraSans-Bold.ttf");
+
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    size: Size::new(Val::Percent(22.0), Val::Auto),
+                    min_size: Size::new(Val::Px(220.0), Val::Auto),
+                    max_size: Size::new(Val::Px(360.0), Val::Undefined),
+                    position_type: PositionType::Absolute,
+                    position: UiRect {
+                        right: Val::Percent(1.0),
+                        top: Val::Percent(12.0),
+                        ..default()
+                    },
+                    flex_direction: FlexDirection::Column,
+                    padding: UiRect::all(Val::Px(8.0)),
+                    ..default()
+                },
+                background_color: Color::rgba(0.0, 0.0, 0.0, 0.6).into(),
+                ..default()
+            },
+            TowerInfoRoot,
+        ))
+        .with_children(|commands| {
+            commands.spawn((
+                TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        font: font.clone(),
+                        font_size: 20.0,
+                        color: Color::BEIGE,
+                    },
+                ),
+                TowerInfoText,
+                ScaledFont { base_size: 20.0 },
+            ));
+
+            spawn_panel_button(commands, &font, "Upgrade", Color::DARK_GREEN, UpgradeButton);
+            spawn_panel_button(commands, &font, "Sell", Color::MAROON, SellButton);
+            spawn_panel_button(
+                commands,
+                &font,
+                "Targeting",
+                Color::MIDNIGHT_BLUE,
+                TargetingButton,
+            );
+        });
+}
+
+fn spawn_panel_button(
+    commands: &mut ChildBuilder,
+    font: &Handle<Font>,
+    text: &str,
+    color: Color,
+    marker: impl Component,
+) {
+    commands
+        .spawn((
+            ButtonBundle {
+                style: Style {
+                    size: Size::new(Val::Percent(100.0), Val::Px(36.0)),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    margin: UiRect::top(Val::Px(6.0)),
+                    ..default()
+                },
+                background_color: color.into(),
+                ..default()
+            },
+            marker,
+        ))
+        .with_children(|commands| {
+            commands.spawn(TextBundle::from_section(
+                text,
+                TextStyle {
+                    font: font.clone(),
+                    font_size: 22.0,
+                    color: Color::BEIGE,
+                },
+            ));
+        });
+}
+
+pub(super) fn update_info_panel(
+    mut text: Query<&mut Text, With<TowerInfoText>>,
+    towers: Query<(
+        &Selection,
+        &Tower,
+        &TowerType,
+        &TowerRecord,
+        &TargetingMode,
+        &StatModifiers,
+    )>,
+) {
+    let Ok(mut text) = text.get_single_mut() else {
+        return;
+    };
+    let Some((_, tower, tower_type, record, targeting, modifiers)) =
+        towers.iter().find(|(selection, ..)| selection.selected())
+    else {
+        return;
+    };
+
+    // Effective value, followed by the base one while something modifies it
+    let stat = |value: String, base: String| {
+        if value == base {
+            value
+        } else {
+            format!("{} (base {})", value, base)
+        }
+    };
+    let range = stat(
+        format!("{:.1}", tower.range),
+        format!("{:.1}", tower.base.range),
+    );
+    let interval = stat(
+        format!("{:.2}s", tower.shooting_timer.duration().as_secs_f32()),
+        format!("{:.2}s", tower.base.fire_interval),
+    );
+    let damage = stat(tower.damage.to_string(), tower.base.damage.to_string());
+    let accuracy = record.accuracy().map_or("-".to_string(), |accuracy| {
+        format!("{:.0}%", accuracy * 100.0)
+    });
+    let upgrade = if tower.level < MAX_TOWER_LEVEL {
+        format!("Upgrade cost: {}", tower_type.upgrade_cost(tower.level))
+    } else {
+        "Max level".to_string()
+    };
+
+    let details = match (tower_type.aura(), tower_type.farm()) {
+        (Some(aura), _) => format!(
+            "Aura range: {}\n\
+             Aura: {}",
+            range,
+            aura.at_level(tower.level),
+        ),
+        (_, Some(farm)) => format!(
+            "Harvest: +{} per wave\n\
+             Harvested: {}",
+            farm.harvest_at(tower.level),
+            record.harvested,
+        ),
+        _ => {
+            let buffs = if tower.is_modified() {
+                format!("{} ({})", modifiers.stack.len(), modifiers.total())
+            } else {
+                "none".to_string()
+            };
+            format!(
+                "Range: {}\n\
+                 Fire interval: {}\n\
+                 Damage: {}\n\
+                 Buffs: {}\n\
+                 Kills: {}\n\
+                 Damage dealt: {}\n\
+                 Accuracy: {} ({} missed)\n\
+                 Targeting: {:?}",
+                range,
+                interval,
+                damage,
+                buffs,
+                record.kills,
+                record.damage_dealt,
+                accuracy,
+                record.misses,
+                targeting,
+            )
+        }
+    };
+
+    text.sections[0].value = format!(
+        "{}\n\
+         Level: {}/{}\n\
+         {}\n\
+         Invested: {}\n\
+         {}\n\
+         Sell value: {}",
+        tower_type.name(),
+        tower.level,
+        MAX_TOWER_LEVEL,
+        details,
+        record.invested,
+        upgrade,
+        record.sell_value(),
+    );
+}
+
+pub(super) fn upgrade_button_clicked(
+    interactions: Query<&Interaction, (With<UpgradeButton>, Changed<Interaction>)>,
+    mut towers: Query<(&Selection, &mut Tower, &TowerType, &mut TowerRecord)>,
+    mut player: Query<&mut Player>,
+) {
+    let mut player = player.single_mut();
+
+    for interaction in &interactions {
+        if matches!(interaction, Interaction::Clicked) {
+            for (selection, mut tower, tower_type, mut record) in &mut towers {
+                if selection.selected() {
+                    upgrade_tower(&mut player, &mut tower, *tower_type, &mut record);
+                }
+            }
+        }
+    }
+}
+
+pub(super) fn sell_button_clicked(
+    mut commands: Commands,
+    interactions: Query<&Interaction, (With<SellButton>, Changed<Interaction>)>,
+    towers: Query<(Entity, &Selection, &TowerRecord, &Transform), With<Tower>>,
+    mut player: Query<&mut Player>,
+    mut sfx: EventWriter<SfxEvent>,
+    assets: Res<GameAssets>,
+) {
+    let mut player = player.single_mut();
+
+    for interaction in &interactions {
+        if matches!(interaction, Interaction::Clicked) {
+            for (entity, selection, record, transform) in &towers {
+                if selection.selected() {
+                    sell_tower(
+                        &mut commands,
+                        &assets,
+                        &mut player,
+                        &mut sfx,
+                        entity,
+                        record,
+                        transform.translation,
+                    );
+                }
+            }
+        }
+    }
+}
+
+pub(super) fn targeting_button_clicked(
+    interactions: Query<&Interaction, (With<TargetingButton>, Changed<Interaction>)>,
+    mut towers: Query<(&Selection, &mut TargetingMode)>,
+) {
+    for interaction in &interactions {
+        if matches!(interaction, Interaction::Clicked) {
+            for (selection, mut targeting) in &mut towers {
+                if selection.selected() {
+                    *targeting = targeting.next();
+                }
+            }
+        }
+    }
+}
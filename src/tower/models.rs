@@ -0,0 +1,206 @@
+//! Towers without a modelled glTF, assembled from primitive meshes instead.
+
+use bevy::prelude::*;
+
+struct Part {
+    mesh: Mesh,
+    color: Color,
+    transform: Transform,
+}
+
+fn build_scene(
+    parts: Vec<Part>,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+) -> Scene {
+    let mut world = World::new();
+    for part in parts {
+        world.spawn(PbrBundle {
+            mesh: meshes.add(part.mesh),
+            material: materials.add(part.color.into()),
+            transform: part.transform,
+            ..default()
+        });
+    }
+    Scene::new(world)
+}
+
+fn plinth(color: Color) -> Part {
+    Part {
+        mesh: shape::Cylinder {
+            radius: 0.35,
+            height: 0.5,
+            ..default()
+        }
+        .into(),
+        color,
+        transform: Transform::from_xyz(0.0, 0.25, 0.0),
+    }
+}
+
+pub fn pea_shooter_scene(
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+) -> Scene {
+    let green = Color::rgb(0.4, 0.75, 0.25);
+    let parts = vec![
+        plinth(Color::rgb(0.45, 0.42, 0.38)),
+        Part {
+            mesh: shape::Cylinder {
+                radius: 0.06,
+                height: 0.7,
+                ..default()
+            }
+            .into(),
+            color: Color::rgb(0.25, 0.5, 0.2),
+            transform: Transform::from_xyz(0.0, 0.85, 0.0),
+        },
+        Part {
+            mesh: shape::UVSphere {
+                radius: 0.25,
+                ..default()
+            }
+            .into(),
+            color: green,
+            transform: Transform::from_xyz(0.0, 1.35, 0.0),
+        },
+        Part {
+            mesh: shape::Cylinder {
+                radius: 0.1,
+                height: 0.35,
+                ..default()
+            }
+            .into(),
+            color: green,
+            transform: Transform::from_xyz(0.25, 1.38, 0.0)
+                .with_rotation(Quat::from_rotation_z(std::f32::consts::FRAC_PI_2)),
+        },
+    ];
+    build_scene(parts, meshes, materials)
+}
+
+pub fn laser_carrot_scene(
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+) -> Scene {
+    let leaf = |angle: f32| Part {
+        mesh: shape::Cylinder {
+            radius: 0.03,
+            height: 0.35,
+            ..default()
+        }
+        .into(),
+        color: Color::rgb(0.25, 0.65, 0.25),
+        transform: Transform::from_xyz(0.0, 1.65, 0.0)
+            .with_rotation(Quat::from_rotation_y(angle) * Quat::from_rotation_z(0.35)),
+    };
+
+    let parts = vec![
+        plinth(Color::rgb(0.45, 0.42, 0.38)),
+        Part {
+            mesh: shape::Capsule {
+                radius: 0.18,
+                depth: 0.7,
+                ..default()
+            }
+            .into(),
+            color: Color::rgb(0.95, 0.5, 0.1),
+            transform: Transform::from_xyz(0.0, 1.05, 0.0),
+        },
+        leaf(0.0),
+        leaf(2.1),
+        leaf(4.2),
+    ];
+    build_scene(parts, meshes, materials)
+}
+
+pub fn compost_scene(meshes: &mut Assets<Mesh>, materials: &mut Assets<StandardMaterial>) -> Scene {
+    let scrap = |x: f32, z: f32, color: Color| Part {
+        mesh: shape::UVSphere {
+            radius: 0.08,
+            ..default()
+        }
+        .into(),
+        color,
+        transform: Transform::from_xyz(x, 0.95, z),
+    };
+
+    let parts = vec![
+        plinth(Color::rgb(0.45, 0.42, 0.38)),
+        Part {
+            mesh: shape::UVSphere {
+                radius: 0.45,
+                ..default()
+            }
+            .into(),
+            color: Color::rgb(0.35, 0.24, 0.12),
+            transform: Transform::from_xyz(0.0, 0.65, 0.0).with_scale(Vec3::new(1.0, 0.7, 1.0)),
+        },
+        scrap(0.15, 0.1, Color::rgb(0.95, 0.92, 0.85)),
+        scrap(-0.12, 0.18, Color::rgb(0.85, 0.2, 0.1)),
+        scrap(0.0, -0.2, Color::rgb(0.95, 0.5, 0.1)),
+        Part {
+            mesh: shape::Cylinder {
+                radius: 0.025,
+                height: 0.35,
+                ..default()
+            }
+            .into(),
+            color: Color::rgb(0.3, 0.7, 0.25),
+            transform: Transform::from_xyz(-0.05, 1.1, -0.02),
+        },
+        Part {
+            mesh: shape::UVSphere {
+                radius: 0.09,
+                ..default()
+            }
+            .into(),
+            color: Color::rgb(0.35, 0.8, 0.3),
+            transform: Transform::from_xyz(-0.05, 1.3, -0.02).with_scale(Vec3::new(1.6, 0.5, 0.9)),
+        },
+    ];
+    build_scene(parts, meshes, materials)
+}
+
+pub fn pumpkin_patch_scene(
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+) -> Scene {
+    let pumpkin = |x: f32, z: f32, size: f32| {
+        [
+            Part {
+                mesh: shape::UVSphere {
+                    radius: size,
+                    ..default()
+                }
+                .into(),
+                color: Color::rgb(0.95, 0.55, 0.1),
+                transform: Transform::from_xyz(x, 0.55 + size * 0.6, z)
+                    .with_scale(Vec3::new(1.0, 0.7, 1.0)),
+            },
+            Part {
+                mesh: shape::Cylinder {
+                    radius: 0.025,
+                    height: 0.1,
+                    ..default()
+                }
+                .into(),
+                color: Color::rgb(0.35, 0.5, 0.2),
+                transform: Transform::from_xyz(x, 0.55 + size * 1.3, z),
+            },
+        ]
+    };
+
+    let mut parts = vec![
+        plinth(Color::rgb(0.45, 0.42, 0.38)),
+        Part {
+            mesh: shape::Box::new(0.8, 0.1, 0.8).into(),
+            color: Color::rgb(0.35, 0.24, 0.12),
+            transform: Transform::from_xyz(0.0, 0.55, 0.0),
+        },
+    ];
+    parts.extend(pumpkin(0.15, 0.12, 0.18));
+    parts.extend(pumpkin(-0.18, -0.05, 0.14));
+    parts.extend(pumpkin(0.05, -0.22, 0.1));
+    build_scene(parts, meshes, materials)
+}
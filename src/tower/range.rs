@@ -0,0 +1,86 @@
+use bevy::{pbr::NotShadowCaster, prelude::*};
+use bevy_mod_picking::*;
+
+use crate::*;
+
+const RANGE_INDICATOR_HEIGHT: f32 = 0.02;
+
+pub(super) fn spawn_range_indicator(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    commands.spawn((
+        PbrBundle {
+            mesh: meshes.add(shape::Circle::new(1.0).into()),
+            material: materials.add(StandardMaterial {
+                base_color: Color::rgba(0.3, 0.6, 1.0, 0.25),
+                alpha_mode: AlphaMode::Blend,
+                unlit: true,
+                ..default()
+            }),
+            // The circle mesh faces +Z, lay it flat on the ground
+            transform: Transform::from_rotation(Quat::from_rotation_x(
+                -std::f32::consts::FRAC_PI_2,
+            )),
+            visibility: Visibility::Hidden,
+            ..default()
+        },
+        NotShadowCaster,
+        RangeIndicator,
+        Name::new("Range_Indicator"),
+    ));
+}
+
+pub(super) fn despawn_range_indicator(
+    mut commands: Commands,
+    indicators: Query<Entity, With<RangeIndicator>>,
+) {
+    for indicator_entity in indicators.iter() {
+        commands.entity(indicator_entity).despawn_recursive();
+    }
+}
+
+/// Shows the range of, in priority order: the tower type under a hovered build
+/// button, the hovered tower, or the selected tower.
+pub(super) fn update_range_indicator(
+    mut indicator: Query<(&mut Transform, &mut Visibility), With<RangeIndicator>>,
+    buttons: Query<(&Interaction, &TowerType), With<TowerButtonState>>,
+    bases: Query<(&Selection, &GlobalTransform), With<TowerBase>>,
+    towers: Query<(&Tower, &Hover, &Selection, &GlobalTransform)>,
+) {
+    let Ok((mut transform, mut visibility)) = indicator.get_single_mut() else {
+        return;
+    };
+
+    let build_preview = buttons
+        .iter()
+        .find(|(interaction, _)| matches!(interaction, Interaction::Hovered))
+        .and_then(|(_, tower_type)| {
+            let (_, base_transform) = bases.iter().find(|(selection, _)| selection.selected())?;
+            Some((base_transform.translation(), tower_type.tower().range))
+        });
+
+    let shown = build_preview
+        .or_else(|| {
+            towers
+                .iter()
+                .find(|(_, hover, _, _)| hover.hovered())
+                .map(|(tower, _, _, transform)| (transform.translation(), tower.range))
+        })
+        .or_else(|| {
+            towers
+                .iter()
+                .find(|(_, _, selection, _)| selection.selected())
+                .map(|(tower, _, _, transform)| (transform.translation(), tower.range))
+        });
+
+    match shown {
+        Some((position, range)) => {
+            *visibility = Visibility::Inherited;
+            transform.translation = Vec3::new(position.x, RANGE_INDICATOR_HEIGHT, position.z);
+            transform.scale = Vec3::splat(range);
+        }
+        None => *visibility = Visibility::Hidden,
+    }
+}
@@ -1,83 +1,256 @@
-use bevy::{prelude::*, utils::FloatOrd};
+use bevy::{pbr::NotShadowCaster, prelude::*, utils::FloatOrd};
+use bevy_mod_picking::*;
 
 use crate::*;
 
-pub(super) fn spawn_tower(
-    commands: &mut Commands,
-    assets: &GameAssets,
-    position: Vec3,
-    tower_type: TowerType,
-) -> Entity {
-    let (tower_scene, tower) = tower_type.get_tower(assets);
+pub fn spawn_tower_base(commands: &mut Commands, assets: &GameAssets, position: Vec3) -> Entity {
     commands
         .spawn((
-            SpatialBundle {
-                transform: Transform::from_translation(position),
-                ..default()
+            SpatialBundle::from_transform(Transform::from_translation(position)),
+            Name::new("Tower_Base"),
+            TowerBase,
+            assets.collider_mesh.clone(),
+            Highlighting {
+                initial: assets.base_collider_color.clone(),
+                hovered: Some(assets.selected_collider_color.clone()),
+                pressed: Some(assets.selected_collider_color.clone()),
+                selected: Some(assets.selected_collider_color.clone()),
             },
-            tower_type,
-            tower,
-            Name::new(format!("{:?}_Tower", tower_type)),
+            assets.base_collider_color.clone(),
+            NotShadowCaster,
+            PickableBundle::default(),
         ))
         .with_children(|commands| {
             commands.spawn(SceneBundle {
-                scene: tower_scene,
+                scene: assets.tower_base_scene.clone(),
                 transform: Transform::from_xyz(0.0, -0.8, 0.0),
-                ..default()
+                ..Default::default()
             });
         })
         .id()
 }
 
+pub(super) fn spawn_tower(
+    commands: &mut Commands,
+    assets: &GameAssets,
+    position: Vec3,
+    tower_type: TowerType,
+) -> Entity {
+    let (tower_scene, tower) = tower_type.get_tower(assets);
+    let mut tower_commands = commands.spawn((
+        SpatialBundle {
+            transform: Transform::from_translation(position),
+            ..default()
+        },
+        tower_type,
+        tower,
+        TowerRecord {
+            invested: tower_type.cost(),
+            ..default()
+        },
+        TargetingMode::default(),
+        StatModifiers::default(),
+        Name::new(format!("{:?}_Tower", tower_type)),
+        assets.collider_mesh.clone(),
+        assets.tower_collider_color.clone(),
+        Highlighting {
+            initial: assets.tower_collider_color.clone(),
+            hovered: Some(assets.selected_collider_color.clone()),
+            pressed: Some(assets.selected_collider_color.clone()),
+            selected: Some(assets.selected_collider_color.clone()),
+        },
+        NotShadowCaster,
+        PickableBundle::default(),
+    ));
+    tower_commands.with_children(|commands| {
+        commands.spawn(SceneBundle {
+            scene: tower_scene,
+            transform: Transform::from_xyz(0.0, -0.8, 0.0),
+            ..default()
+        });
+    });
+
+    if let Some(aura) = tower_type.aura() {
+        tower_commands.insert(aura);
+    }
+
+    if let Some(farm) = tower_type.farm() {
+        tower_commands.insert(farm);
+    }
+
+    if let Some(beam) = tower_type.beam() {
+        tower_commands.insert(beam).with_children(|commands| {
+            commands.spawn((
+                PbrBundle {
+                    mesh: assets.beam_mesh.clone(),
+                    material: assets.beam_material.clone(),
+                    visibility: Visibility::Hidden,
+                    ..default()
+                },
+                BeamVisual,
+                NotShadowCaster,
+                Name::new("Beam"),
+            ));
+        });
+    }
+
+    tower_commands.id()
+}
+
+/// Replaces `base` with a new tower if the player can afford it.
+pub(super) fn build_tower(
+    commands: &mut Commands,
+    assets: &GameAssets,
+    player: &mut Player,
+    sfx: &mut EventWriter<SfxEvent>,
+    base: Entity,
+    position: Vec3,
+    tower_type: TowerType,
+) -> Option<Entity> {
+    let cost = tower_type.cost();
+    if player.money < cost {
+        return None;
+    }
+
+    player.money -= cost;
+    sfx.send(SfxEvent::Build);
+    commands.entity(base).despawn_recursive();
+    Some(spawn_tower(commands, assets, position, tower_type))
+}
+
+pub(super) fn upgrade_tower(
+    player: &mut Player,
+    tower: &mut Tower,
+    tower_type: TowerType,
+    record: &mut TowerRecord,
+) -> bool {
+    let cost = tower_type.upgrade_cost(tower.level);
+    if tower.level >= MAX_TOWER_LEVEL || player.money < cost {
+        return false;
+    }
+
+    player.money -= cost;
+    record.invested += cost;
+    tower.upgrade(tower_type.attack_kind());
+    true
+}
+
+/// Refunds part of the tower's value and puts an empty base back in its place.
+pub(super) fn sell_tower(
+    commands: &mut Commands,
+    assets: &GameAssets,
+    player: &mut Player,
+    sfx: &mut EventWriter<SfxEvent>,
+    tower: Entity,
+    record: &TowerRecord,
+    position: Vec3,
+) {
+    player.money += record.sell_value();
+    sfx.send(SfxEvent::Sell);
+    commands.entity(tower).despawn_recursive();
+    spawn_tower_base(commands, assets, position);
+}
+
 pub(super) fn despawn_towers(mut commands: Commands, towers: Query<Entity, With<Tower>>) {
     for tower_entity in towers.iter() {
         commands.entity(tower_entity).despawn_recursive();
     }
 }
 
-pub(super) fn tower_shooting(
-    mut commands: Commands,
-    mut towers: Query<(Entity, &mut Tower, &TowerType, &GlobalTransform)>,
-    targets: Query<&GlobalTransform, With<Target>>,
-    bullet_assets: Res<GameAssets>,
+/// Picks the target `targeting` prefers among those within `range` of `origin`.
+pub(super) fn choose_target(
+    targets: &Query<(Entity, &GlobalTransform, &Target, &Health)>,
+    path: &TargetPath,
+    origin: Vec3,
+    range: f32,
+    targeting: TargetingMode,
+) -> Option<(Entity, Vec3)> {
+    let in_range = targets.iter().filter(|(_, target_transform, _, _)| {
+        Vec3::distance(target_transform.translation(), origin) < range
+    });
+    let remaining = |(_, target_transform, target, _): &(_, &GlobalTransform, &Target, _)| {
+        FloatOrd(path.remaining_distance(target.path_index, target_transform.translation()))
+    };
+    let distance = |(_, target_transform, _, _): &(_, &GlobalTransform, _, _)| {
+        FloatOrd(Vec3::distance(target_transform.translation(), origin))
+    };
+    let chosen = match targeting {
+        TargetingMode::First => in_range.min_by_key(remaining),
+        TargetingMode::Last => in_range.max_by_key(remaining),
+        TargetingMode::Closest => in_range.min_by_key(distance),
+        TargetingMode::Strongest => in_range.max_by_key(|(_, _, _, health)| health.value),
+    };
+    chosen.map(|(entity, target_transform, _, _)| (entity, target_transform.translation()))
+}
+
+pub fn tower_shooting(
+    mut towers: Query<(
+        Entity,
+        &mut Tower,
+        &TowerType,
+        &TargetingMode,
+        &GlobalTransform,
+    )>,
+    targets: Query<(Entity, &GlobalTransform, &Target, &Health)>,
+    path: Res<TargetPath>,
+    mut fired_events: EventWriter<TowerFiredEvent>,
     time: Res<Time>,
 ) {
-    for (tower_entity, mut tower, tower_type, transform) in &mut towers {
+    for (tower_entity, mut tower, tower_type, targeting, transform) in &mut towers {
+        // Beams and auras run on their own timers
+        if !matches!(
+            tower_type.attack_kind(),
+            AttackKind::Projectile | AttackKind::Hitscan
+        ) {
+            continue;
+        }
+
         tower.shooting_timer.tick(time.delta());
         if tower.shooting_timer.just_finished() {
             let bullet_spawn = transform.translation() + tower.bullet_offset;
-            let direction = targets
-                .iter()
-                .filter(|target_transform| {
-                    Vec3::distance(target_transform.translation(), bullet_spawn) < tower.range
-                })
-                .min_by_key(|target_transform| {
-                    FloatOrd(Vec3::distance(target_transform.translation(), bullet_spawn))
-                })
-                .map(|closest_target| closest_target.translation() - bullet_spawn);
-
-            if let Some(direction) = direction {
-                let (model, bullet) = tower_type.get_bullet(direction, &bullet_assets);
-
-                commands.entity(tower_entity).with_children(|commands| {
-                    commands.spawn((
-                        SceneBundle {
-                            scene: model,
-                            transform: Transform::from_translation(tower.bullet_offset),
-                            ..default()
-                        },
-                        bullet,
-                        Lifetime {
-                            timer: Timer::from_seconds(10.0, TimerMode::Once),
-                        },
-                        Name::new("Bullet"),
-                    ));
+            let chosen = choose_target(&targets, &path, bullet_spawn, tower.range, *targeting);
+
+            if let Some((target, target_position)) = chosen {
+                fired_events.send(TowerFiredEvent {
+                    tower: tower_entity,
+                    tower_type: *tower_type,
+                    target,
+                    position: bullet_spawn,
+                    direction: target_position - bullet_spawn,
+                    damage: tower.damage,
+                    range: tower.range,
                 });
             }
         }
     }
 }
 
+pub(super) fn record_tower_hits(
+    mut hit_events: EventReader<BulletHitEvent>,
+    mut records: Query<&mut TowerRecord>,
+) {
+    for event in hit_events.iter() {
+        if let Ok(mut record) = records.get_mut(event.source) {
+            record.hits += 1;
+            record.damage_dealt += event.damage.max(0) as u32;
+            if event.lethal {
+                record.kills += 1;
+            }
+        }
+    }
+}
+
+pub(super) fn record_tower_misses(
+    mut miss_events: EventReader<BulletMissEvent>,
+    mut records: Query<&mut TowerRecord>,
+) {
+    for event in miss_events.iter() {
+        if let Ok(mut record) = records.get_mut(event.source) {
+            record.misses += 1;
+        }
+    }
+}
+
 // fn spawn_tomato_tower(commands: &mut Commands, game_assets: &GameAssets, position: Vec3) -> Entity {
 //     commands
 //         .spawn((
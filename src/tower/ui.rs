@@ -2,7 +2,7 @@ use bevy::{ecs::query::QuerySingleError, prelude::*};
 
 use crate::*;
 
-use super::systems::spawn_tower;
+use super::systems::build_tower;
 
 pub(super) fn grey_tower_buttons(
     mut buttons: Query<(&mut BackgroundColor, &mut TowerButtonState)>,
@@ -24,23 +24,26 @@ pub(super) fn grey_tower_buttons(
 pub(super) fn tower_button_clicked(
     interactions: Query<(&Interaction, &TowerType, &TowerButtonState), Changed<Interaction>>,
     mut commands: Commands,
-    selection: Query<(Entity, &Selection, &Transform)>,
+    selection: Query<(Entity, &Selection, &Transform), With<TowerBase>>,
     mut player: Query<&mut Player>,
+    mut sfx: EventWriter<SfxEvent>,
     assets: Res<GameAssets>,
 ) {
     let mut player = player.single_mut();
 
-    for (interaction, tower_type, button_state) in &interactions {
+    for (interaction, tower_type, _) in &interactions {
         if matches!(interaction, Interaction::Clicked) {
             for (entity, selection, transform) in &selection {
                 if selection.selected() {
-                    if player.money >= button_state.cost {
-                        player.money -= button_state.cost;
-                        commands.entity(entity).despawn_recursive();
-                    }
-
-                    commands.entity(entity).despawn_recursive();
-                    spawn_tower(&mut commands, &assets, transform.translation, *tower_type);
+                    build_tower(
+                        &mut commands,
+                        &assets,
+                        &mut player,
+                        &mut sfx,
+                        entity,
+                        transform.translation,
+                        *tower_type,
+                    );
                 }
             }
         }
@@ -52,10 +55,13 @@ pub(super) fn create_ui(commands: &mut Commands, asset_server: &AssetServer) {
         asset_server.load("tomato_tower.png"),
         asset_server.load("potato_tower.png"),
         asset_server.load("cabbage_tower.png"),
+        asset_server.load("pea_shooter_tower.png"),
+        asset_server.load("laser_carrot_tower.png"),
+        asset_server.load("compost_tower.png"),
+        asset_server.load("pumpkin_patch_tower.png"),
     ];
 
-    let towers = [TowerType::Tomato, TowerType::Potato, TowerType::Cabbage];
-    let costs = [50, 80, 110];
+    let towers = TowerType::ALL;
 
     commands
         .spawn((
@@ -74,7 +80,10 @@ pub(super) fn create_ui(commands: &mut Commands, asset_server: &AssetServer) {
                 commands.spawn((
                     ButtonBundle {
                         style: Style {
-                            size: Size::new(Val::Percent(15.0 * 9.0 / 16.0), Val::Percent(15.0)),
+                            size: Size::new(Val::Auto, Val::Percent(15.0)),
+                            min_size: Size::new(Val::Auto, Val::Px(80.0)),
+                            max_size: Size::new(Val::Auto, Val::Px(220.0)),
+                            aspect_ratio: Some(9.0 / 16.0),
                             align_self: AlignSelf::FlexEnd,
                             margin: UiRect::all(Val::Percent(2.0)),
                             ..default()
@@ -83,7 +92,7 @@ pub(super) fn create_ui(commands: &mut Commands, asset_server: &AssetServer) {
                         ..default()
                     },
                     TowerButtonState {
-                        cost: costs[i],
+                        cost: towers[i].cost(),
                         affordable: false,
                     },
                     towers[i],
@@ -95,7 +104,7 @@ pub(super) fn create_ui(commands: &mut Commands, asset_server: &AssetServer) {
 pub(super) fn create_ui_on_selection(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
-    selections: Query<&Selection>,
+    selections: Query<&Selection, With<TowerBase>>,
     root: Query<Entity, With<TowerUIRoot>>,
 ) {
     let at_least_one_selected = selections.iter().any(|selection| selection.selected());
@@ -114,3 +123,134 @@ pub(super) fn create_ui_on_selection(
         _ => unreachable!("Too many UI Tower Roots!"),
     }
 }
+
+pub(super) fn tower_button_tooltips(
+    mut commands: Commands,
+    interactions: Query<
+        (Entity, &Interaction, &TowerType, &TowerButtonState),
+        Changed<Interaction>,
+    >,
+    tooltips: Query<(Entity, &Parent), With<TowerTooltip>>,
+    asset_server: Res<AssetServer>,
+) {
+    for (button, interaction, tower_type, _) in &interactions {
+        for (tooltip, parent) in &tooltips {
+            if parent.get() == button {
+                commands.entity(tooltip).despawn_recursive();
+            }
+        }
+
+        if matches!(interaction, Interaction::Hovered) {
+            let tooltip = spawn_tooltip(&mut commands, &asset_server, *tower_type);
+            commands.entity(button).add_child(tooltip);
+        }
+    }
+}
+
+fn spawn_tooltip(
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+    tower_type: TowerType,
+) -> Entity {
+    let tower = tower_type.tower();
+    let bullet = tower_type.bullet(Entity::PLACEHOLDER, Vec3::ZERO, tower.damage);
+    let font = asset_server.load("FiraSans-Bold.ttf");
+    let crit = bullet.crit_chance * 100.0;
+    let stats = match tower_type.attack_kind() {
+        AttackKind::Support => format!(
+            "Cost: {}\n\
+             Aura range: {:.1}\n\
+             Aura: {}",
+            tower_type.cost(),
+            tower.range,
+            tower_type.aura().unwrap_or_default().modifier,
+        ),
+        AttackKind::Income => {
+            let harvest = tower_type
+                .farm()
+                .unwrap_or_default()
+                .harvest_at(tower.level);
+            format!(
+                "Cost: {}\n\
+                 Harvest: +{} per cleared wave\n\
+                 Pays for itself in {} waves",
+                tower_type.cost(),
+                harvest,
+                tower_type.cost().div_ceil(harvest.max(1)),
+            )
+        }
+        kind => {
+            let attack = match (kind, tower_type.beam()) {
+                (AttackKind::Beam, Some(beam)) => {
+                    format!("Beam ramps to {:.0}x damage", beam.max_multiplier)
+                }
+                (AttackKind::Hitscan, _) => "Instant hit".to_string(),
+                _ => format!("Projectile speed: {:.1}", bullet.speed),
+            };
+            format!(
+                "Cost: {}\n\
+                 Damage: {} ({:?})\n\
+                 Range: {:.1}\n\
+                 Fire rate: {:.1}/s\n\
+                 {}, {:.0}% crit",
+                tower_type.cost(),
+                tower.damage,
+                bullet.damage_type,
+                tower.range,
+                1.0 / tower.shooting_timer.duration().as_secs_f32(),
+                attack,
+                crit,
+            )
+        }
+    };
+
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    size: Size::new(Val::Px(260.0), Val::Auto),
+                    position_type: PositionType::Absolute,
+                    position: UiRect {
+                        left: Val::Px(0.0),
+                        bottom: Val::Percent(105.0),
+                        ..default()
+                    },
+                    flex_direction: FlexDirection::Column,
+                    padding: UiRect::all(Val::Px(8.0)),
+                    ..default()
+                },
+                background_color: Color::rgba(0.0, 0.0, 0.0, 0.8).into(),
+                ..default()
+            },
+            TowerTooltip,
+        ))
+        .with_children(|commands| {
+            commands.spawn(TextBundle::from_sections([
+                TextSection::new(
+                    format!("{}\n", tower_type.name()),
+                    TextStyle {
+                        font: font.clone(),
+                        font_size: 22.0,
+                        color: Color::GOLD,
+                    },
+                ),
+                TextSection::new(
+                    format!("{}\n", stats),
+                    TextStyle {
+                        font: font.clone(),
+                        font_size: 16.0,
+                        color: Color::BEIGE,
+                    },
+                ),
+                TextSection::new(
+                    tower_type.description(),
+                    TextStyle {
+                        font,
+                        font_size: 14.0,
+                        color: Color::SILVER,
+                    },
+                ),
+            ]));
+        })
+        .id()
+}
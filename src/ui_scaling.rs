@@ -0,0 +1,43 @@
+use bevy::{
+    prelude::*,
+    window::{PrimaryWindow, WindowResized},
+};
+
+/// Window height the UI font sizes were designed for.
+pub const REFERENCE_HEIGHT: f32 = 720.0;
+
+pub struct UiScalingPlugin;
+
+impl Plugin for UiScalingPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system(scale_fonts);
+    }
+}
+
+/// Keeps a text's font size proportional to the window height.
+#[derive(Component)]
+pub struct ScaledFont {
+    pub base_size: f32,
+}
+
+fn scale_fonts(
+    mut resize_events: EventReader<WindowResized>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut texts: Query<(&mut Text, &ScaledFont)>,
+    added: Query<(), Added<ScaledFont>>,
+) {
+    let resized = resize_events.iter().count() > 0;
+    if !resized && added.is_empty() {
+        return;
+    }
+    let Ok(window) = windows.get_single() else {
+        return;
+    };
+
+    let factor = (window.height() / REFERENCE_HEIGHT).clamp(0.5, 2.0);
+    for (mut text, scaled) in &mut texts {
+        for section in &mut text.sections {
+            section.style.font_size = scaled.base_size * factor;
+        }
+    }
+}
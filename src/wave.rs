@@ -0,0 +1,40 @@
+mod components;
+mod events;
+mod resources;
+mod systems;
+mod ui;
+
+use bevy::prelude::*;
+pub use components::*;
+pub use events::*;
+pub use resources::*;
+use systems::*;
+use ui::*;
+
+use crate::GameState;
+
+pub struct WavePlugin;
+
+impl Plugin for WavePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<WaveList>()
+            .add_event::<WaveStartedEvent>()
+            .add_event::<CallWaveEvent>()
+            .add_event::<WaveClearedEvent>()
+            .add_system(reset_waves.in_schedule(OnEnter(GameState::Gameplay)))
+            .add_systems(
+                (
+                    call_wave_hotkey,
+                    call_wave_button_clicked,
+                    call_wave_early,
+                    wave_countdown,
+                    spawn_wave_targets,
+                    check_wave_cleared,
+                    update_wave_ui,
+                )
+                    .chain()
+                    .in_set(OnUpdate(GameState::Gameplay)),
+            )
+            .add_system(remove_wave_state.in_schedule(OnExit(GameState::Gameplay)));
+    }
+}
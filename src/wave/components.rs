@@ -0,0 +1,8 @@
+use bevy::prelude::*;
+
+/// Top bar text showing the wave number or the countdown to the next one.
+#[derive(Component)]
+pub struct WaveUI;
+
+#[derive(Component)]
+pub struct CallWaveButton;
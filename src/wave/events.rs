@@ -0,0 +1,13 @@
+pub struct WaveStartedEvent {
+    /// Zero-based index into `WaveList::waves`
+    pub wave: usize,
+    /// Countdown time left when the wave was started early
+    pub skipped_seconds: f32,
+}
+
+/// Asks for the next wave to start without waiting out the countdown.
+pub struct CallWaveEvent;
+
+pub struct WaveClearedEvent {
+    pub wave: usize,
+}
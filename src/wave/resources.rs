@@ -0,0 +1,91 @@
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+
+use crate::EnemyType;
+
+pub const WAVE_BREAK_SECONDS: f32 = 15.0;
+pub const SPAWN_INTERVAL_SECONDS: f32 = 1.0;
+
+pub struct Wave {
+    /// Spawned in order, each group fully before the next
+    pub groups: Vec<(EnemyType, u32)>,
+}
+
+impl Wave {
+    pub fn has_boss(&self) -> bool {
+        self.groups
+            .iter()
+            .any(|(enemy_type, _)| *enemy_type == EnemyType::Boss)
+    }
+
+    pub(super) fn spawn_queue(&self) -> VecDeque<EnemyType> {
+        self.groups
+            .iter()
+            .flat_map(|(enemy_type, count)| std::iter::repeat_n(*enemy_type, *count as usize))
+            .collect()
+    }
+}
+
+#[derive(Resource)]
+pub struct WaveList {
+    pub waves: Vec<Wave>,
+    pub spawn_point: Vec3,
+}
+
+impl Default for WaveList {
+    fn default() -> Self {
+        use EnemyType::*;
+
+        Self {
+            waves: vec![
+                Wave {
+                    groups: vec![(Basic, 8)],
+                },
+                Wave {
+                    groups: vec![(Basic, 10), (Splitter, 2)],
+                },
+                Wave {
+                    groups: vec![(Basic, 8), (Healer, 2), (Basic, 4)],
+                },
+                Wave {
+                    groups: vec![(Shielder, 3), (Basic, 8), (Splitter, 3)],
+                },
+                Wave {
+                    groups: vec![(Basic, 6), (Healer, 2), (Shielder, 2), (Boss, 1)],
+                },
+            ],
+            spawn_point: Vec3::new(-4.0, 0.4, 2.5),
+        }
+    }
+}
+
+pub enum WavePhase {
+    /// Waiting for the next wave to start
+    Countdown(Timer),
+    /// Spawning the current wave one target at a time
+    Spawning {
+        queue: VecDeque<EnemyType>,
+        timer: Timer,
+    },
+    /// Everything has spawned, waiting for the field to clear
+    Clearing,
+    /// Every wave has been cleared
+    Finished,
+}
+
+#[derive(Resource)]
+pub struct WaveState {
+    /// Wave currently running, or the next one during a countdown
+    pub current: usize,
+    pub phase: WavePhase,
+}
+
+impl Default for WaveState {
+    fn default() -> Self {
+        Self {
+            current: 0,
+            phase: WavePhase::Countdown(Timer::from_seconds(WAVE_BREAK_SECONDS, TimerMode::Once)),
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use bevy::prelude::*;
+
+use crate::*;
+
+pub(super) fn reset_waves(mut commands: Commands) {
+    commands.insert_resource(WaveState::default());
+}
+
+pub(super) fn remove_wave_state(mut commands: Commands) {
+    commands.remove_resource::<WaveState>();
+}
+
+/// Moves `state` into spawning its current wave.
+pub(super) fn start_wave(state: &mut WaveState, waves: &WaveList) {
+    let mut timer = Timer::from_seconds(SPAWN_INTERVAL_SECONDS, TimerMode::Repeating);
+    // Start at the end of the interval so the first target spawns on the next tick
+    timer.set_elapsed(timer.duration());
+
+    state.phase = WavePhase::Spawning {
+        queue: waves.waves[state.current].spawn_queue(),
+        timer,
+    };
+}
+
+pub(super) fn call_wave_early(
+    mut state: ResMut<WaveState>,
+    waves: Res<WaveList>,
+    mut call_events: EventReader<CallWaveEvent>,
+    mut started_events: EventWriter<WaveStartedEvent>,
+) {
+    if call_events.iter().count() == 0 {
+        return;
+    }
+    let WavePhase::Countdown(timer) = &state.phase else {
+        return;
+    };
+
+    let skipped_seconds = timer.remaining_secs();
+    start_wave(&mut state, &waves);
+    started_events.send(WaveStartedEvent {
+        wave: state.current,
+        skipped_seconds,
+    });
+}
+
+pub(super) fn wave_countdown(
+    mut state: ResMut<WaveState>,
+    waves: Res<WaveList>,
+    mut started_events: EventWriter<WaveStartedEvent>,
+    time: Res<Time>,
+) {
+    let WavePhase::Countdown(timer) = &mut state.phase else {
+        return;
+    };
+
+    if timer.tick(time.delta()).finished() {
+        start_wave(&mut state, &waves);
+        started_events.send(WaveStartedEvent {
+            wave: state.current,
+            skipped_seconds: 0.0,
+        });
+    }
+}
+
+pub(super) fn spawn_wave_targets(
+    mut commands: Commands,
+    mut state: ResMut<WaveState>,
+    waves: Res<WaveList>,
+    assets: Res<GameAssets>,
+    time: Res<Time>,
+) {
+    let WavePhase::Spawning { queue, timer } = &mut state.phase else {
+        return;
+    };
+
+    // Checked a frame after the last spawn so its commands have been applied before
+    // `check_wave_cleared` looks for remaining targets
+    if queue.is_empty() {
+        state.phase = WavePhase::Clearing;
+        return;
+    }
+
+    if timer.tick(time.delta()).just_finished() {
+        if let Some(enemy_type) = queue.pop_front() {
+            spawn_target(&mut commands, &assets, enemy_type, waves.spawn_point, 0);
+        }
+    }
+}
+
+pub(super) fn check_wave_cleared(
+    mut state: ResMut<WaveState>,
+    waves: Res<WaveList>,
+    targets: Query<(), With<Target>>,
+    mut cleared_events: EventWriter<WaveClearedEvent>,
+) {
+    if !matches!(state.phase, WavePhase::Clearing) || !targets.is_empty() {
+        return;
+    }
+
+    cleared_events.send(WaveClearedEvent {
+        wave: state.current,
+    });
+
+    state.current += 1;
+    state.phase = if state.current < waves.waves.len() {
+        WavePhase::Countdown(Timer::from_seconds(WAVE_BREAK_SECONDS, TimerMode::Once))
+    } else {
+        info!("ALL WAVES CLEARED");
+        WavePhase::Finished
+    };
+}
@@ -0,0 +1,57 @@
+use bevy::prelude::*;
+
+use crate::*;
+
+pub(super) fn call_wave_hotkey(actions: ActionInput, mut call_events: EventWriter<CallWaveEvent>) {
+    if actions.just_pressed(Action::CallWave) {
+        call_events.send(CallWaveEvent);
+    }
+}
+
+pub(super) fn call_wave_button_clicked(
+    interactions: Query<&Interaction, (With<CallWaveButton>, Changed<Interaction>)>,
+    mut call_events: EventWriter<CallWaveEvent>,
+) {
+    for interaction in &interactions {
+        if matches!(interaction, Interaction::Clicked) {
+            call_events.send(CallWaveEvent);
+        }
+    }
+}
+
+pub(super) fn update_wave_ui(
+    mut wave_ui: Query<&mut Text, With<WaveUI>>,
+    mut call_button: Query<&mut Visibility, With<CallWaveButton>>,
+    state: Res<WaveState>,
+    waves: Res<WaveList>,
+    rules: Res<EconomyRules>,
+) {
+    let total = waves.waves.len();
+    let (label, can_call) = match &state.phase {
+        WavePhase::Countdown(timer) => (
+            format!(
+                "Wave {}/{} in {:.0}s (+{} to call now)",
+                state.current + 1,
+                total,
+                timer.remaining_secs().ceil(),
+                rules.early_call_bonus(timer.remaining_secs()),
+            ),
+            true,
+        ),
+        WavePhase::Spawning { .. } | WavePhase::Clearing => {
+            (format!("Wave {}/{}", state.current + 1, total), false)
+        }
+        WavePhase::Finished => ("All waves cleared".to_string(), false),
+    };
+
+    for mut text in &mut wave_ui {
+        text.sections[0].value = label.clone();
+    }
+    for mut visibility in &mut call_button {
+        *visibility = if can_call {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+    }
+}